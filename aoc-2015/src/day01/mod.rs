@@ -0,0 +1,62 @@
+//! [Day 1: Not Quite Lisp](https://adventofcode.com/2015/day/1)
+
+use aoc_lib::{Part1, Part2};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/01.txt"));
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Part1 for Day01 {
+    type A = isize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        input
+            .chars()
+            .fold(0, |floor, instruction| match instruction {
+                '(' => floor + 1,
+                ')' => floor - 1,
+                _ => unreachable!(),
+            })
+    }
+}
+
+impl Part2 for Day01 {
+    type B = usize;
+
+    fn solve(&self, input: &str) -> Self::B {
+        let mut it = input.chars();
+        it.try_fold(0usize, |floor, instruction| match instruction {
+            '(' => Some(floor + 1),
+            ')' => floor.checked_sub(1),
+            _ => unreachable!(),
+        });
+        input.len() - it.count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day01, INPUT};
+
+    aoc_lib::test_table! {
+        Day01;
+        part1_example: Part1 {
+            "(())" => 0,
+            "()()" => 0,
+            "(((" => 3,
+            "(()(()(" => 3,
+            "))(((((" => 3,
+            "())" => -1,
+            "))(" => -1,
+            ")))" => -3,
+            ")())())" => -3,
+        },
+        part2_example: Part2 {
+            ")" => 1,
+            "()())" => 5,
+        },
+        part1_answer: Part1 { INPUT => 138 },
+        part2_answer: Part2 { INPUT => 1771 },
+    }
+}
@@ -0,0 +1,60 @@
+//! [Day 2: I Was Told There Would Be No Math](https://adventofcode.com/2015/day/2)
+
+use aoc_lib::{Part1, Part2};
+use itertools::Itertools;
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/02.txt"));
+
+#[derive(Default)]
+pub struct Day02;
+
+impl Part1 for Day02 {
+    type A = usize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        input.lines().fold(0, |sum, line| {
+            let (l, w, h) = line
+                .split('x')
+                .map(|v| v.parse().unwrap())
+                .collect_tuple::<(usize, usize, usize)>()
+                .expect("expected 3 elements");
+
+            let lw = l * w;
+            let wh = w * h;
+            let hl = h * l;
+            sum + 2 * lw + 2 * wh + 2 * hl + [lw, wh, hl].iter().min().unwrap()
+        })
+    }
+}
+
+impl Part2 for Day02 {
+    type B = usize;
+
+    fn solve(&self, input: &str) -> Self::B {
+        input.lines().fold(0, |sum, line| {
+            let (l, w, h) = line
+                .split('x')
+                .map(|v| v.parse().unwrap())
+                .collect_tuple::<(usize, usize, usize)>()
+                .expect("Expected 3 elements");
+
+            let lw = l * 2 + w * 2;
+            let wh = w * 2 + h * 2;
+            let hl = h * 2 + l * 2;
+            sum + w * h * l + [lw, wh, hl].iter().min().unwrap()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day02, INPUT};
+
+    aoc_lib::test_table! {
+        Day02;
+        part1_example: Part1 { "2x3x4" => 58, "1x1x10" => 43 },
+        part2_example: Part2 { "2x3x4" => 34, "1x1x10" => 14 },
+        part1_answer: Part1 { INPUT => 1588178 },
+        part2_answer: Part2 { INPUT => 3783758 },
+    }
+}
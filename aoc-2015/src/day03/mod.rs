@@ -0,0 +1,71 @@
+//! [Day 3: Perfectly Spherical Houses in a Vacuum](https://adventofcode.com/2015/day/3)
+
+use std::{cell::Cell, collections::HashSet};
+
+use aoc_lib::{Part1, Part2};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/03.txt"));
+
+#[derive(Default)]
+pub struct Day03;
+
+impl Part1 for Day03 {
+    type A = usize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        let mut houses = HashSet::with_capacity(input.len());
+        houses.insert((0, 0));
+
+        let mut santa = (0, 0);
+        for move_xy in input.chars() {
+            match move_xy {
+                '^' => santa.1 += 1,
+                'v' => santa.1 -= 1,
+                '>' => santa.0 += 1,
+                '<' => santa.0 -= 1,
+                _ => unreachable!(),
+            };
+            houses.insert(santa);
+        }
+
+        houses.len()
+    }
+}
+
+impl Part2 for Day03 {
+    type B = usize;
+
+    fn solve(&self, input: &str) -> Self::B {
+        let mut houses = HashSet::with_capacity(input.len());
+        houses.insert((0, 0));
+
+        let santas = vec![Cell::new((0, 0)); 2];
+        for (santa, direction) in santas.iter().cycle().zip(input.chars()) {
+            let mut current_santa = santa.get();
+            match direction {
+                '^' => current_santa.1 += 1,
+                'v' => current_santa.1 -= 1,
+                '>' => current_santa.0 += 1,
+                '<' => current_santa.0 -= 1,
+                _ => unreachable!(),
+            };
+            houses.insert(current_santa);
+            santa.set(current_santa);
+        }
+
+        houses.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day03, INPUT};
+
+    aoc_lib::test_table! {
+        Day03;
+        part1_example: Part1 { ">" => 2, "^>v<" => 4, "^v^v^v^v^v" => 2 },
+        part2_example: Part2 { ">" => 2, "^>v<" => 3, "^v^v^v^v^v" => 11 },
+        part1_answer: Part1 { INPUT => 2565 },
+        part2_answer: Part2 { INPUT => 2639 },
+    }
+}
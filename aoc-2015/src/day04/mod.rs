@@ -0,0 +1,90 @@
+//! [Day 4: The Ideal Stocking Stuffer](https://adventofcode.com/2015/day/4)
+
+use std::fmt::Write;
+
+use aoc_lib::{Error, Result, TryPart1, TryPart2};
+use md5::{Digest, Md5};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/04.txt"));
+
+#[derive(Default)]
+pub struct Day04;
+
+/// Amount of hashes between polls of [check_cancelled](aoc_lib::check_cancelled).
+const CHECK_INTERVAL: usize = 1 << 14;
+
+impl TryPart1 for Day04 {
+    type A = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        let mut secret = input.to_owned();
+        let mut hasher = Md5::new();
+
+        for i in 0..usize::MAX {
+            if i % CHECK_INTERVAL == 0 {
+                aoc_lib::check_cancelled()?;
+            }
+
+            write!(secret, "{}", i).expect("could not write to secret");
+            hasher.update(&secret);
+
+            let result = hasher.finalize_reset();
+            if result[..2] == [0, 0] && result[2] <= 0x0F {
+                return Ok(i);
+            }
+
+            secret.truncate(input.len());
+        }
+
+        Err(Error::no_answer("ran out of numbers"))
+    }
+}
+
+impl TryPart2 for Day04 {
+    type B = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        let mut secret = input.to_owned();
+        let mut hasher = Md5::new();
+
+        for i in 0..usize::MAX {
+            if i % CHECK_INTERVAL == 0 {
+                aoc_lib::check_cancelled()?;
+            }
+
+            write!(secret, "{}", i).expect("could not write to secret");
+            hasher.update(&secret);
+
+            if hasher.finalize_reset()[..3] == [0, 0, 0] {
+                return Ok(i);
+            }
+
+            secret.truncate(input.len());
+        }
+
+        Err(Error::no_answer("ran out of numbers"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day04, INPUT};
+    use std::time::Duration;
+
+    use aoc_lib::{ErrorKind, TryPart2};
+
+    aoc_lib::test_table! {
+        Day04;
+        part1_example: Part1 { "abcdef" => 609043, "pqrstuv" => 1048970 },
+        part1_answer: Part1 { INPUT => 254575 },
+        part2_answer: Part2 { INPUT => 1038736 },
+    }
+
+    #[test]
+    fn part2_cancelled() {
+        let result = aoc_lib::watch(Duration::from_millis(1), || {
+            TryPart2::try_solve(&Day04, INPUT)
+        });
+        assert_eq!(result.unwrap_err().kind, ErrorKind::Timeout);
+    }
+}
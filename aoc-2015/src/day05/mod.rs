@@ -0,0 +1,72 @@
+//! [Day 5: Doesn't He Have Intern-Elves For This?](https://adventofcode.com/2015/day/5)
+
+use aoc_lib::{Part1, Part2};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/05.txt"));
+
+#[derive(Default)]
+pub struct Day05;
+
+impl Part1 for Day05 {
+    type A = usize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        const VOWELS: &[char] = &['a', 'e', 'i', 'o', 'u'];
+        const BAD_STRS: &[&str] = &["ab", "cd", "pq", "xy"];
+
+        input.lines().fold(0, |nice, line| {
+            if line.split(VOWELS).count() > 3
+                && !BAD_STRS.iter().any(|bad_str| line.contains(bad_str))
+                && line.as_bytes().windows(2).any(|pair| pair[0] == pair[1])
+            {
+                nice + 1
+            } else {
+                nice
+            }
+        })
+    }
+}
+
+impl Part2 for Day05 {
+    type B = usize;
+
+    fn solve(&self, input: &str) -> Self::B {
+        input.lines().fold(0, |nice, line| {
+            let is_nice_string = line.as_bytes().windows(2).enumerate().any(|(i, pair)| {
+                line.rfind(std::str::from_utf8(pair).unwrap()) // Find the last occurrence of the current pair
+                    .map(|index| index > i + 1) // Resulting pair must not overlap
+                    .unwrap_or(false)
+            }) && line.as_bytes().windows(3).any(|pair| pair[0] == pair[2]);
+
+            if is_nice_string {
+                nice + 1
+            } else {
+                nice
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day05, INPUT};
+
+    aoc_lib::test_table! {
+        Day05;
+        part1_example: Part1 {
+            "ugknbfddgicrmopn" => 1,
+            "aaa" => 1,
+            "jchzalrnumimnmhp" => 0,
+            "haegwjzuvuyypxyu" => 0,
+            "dvszwmarrgswjxmb" => 0,
+        },
+        part2_example: Part2 {
+            "qjhvhtzxzqqjkmpb" => 1,
+            "xxyxx" => 1,
+            "uurcxstgmygtbstg" => 0,
+            "ieodomkazucvgmuy" => 0,
+        },
+        part1_answer: Part1 { INPUT => 238 },
+        part2_answer: Part2 { INPUT => 69 },
+    }
+}
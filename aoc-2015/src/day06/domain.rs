@@ -0,0 +1,81 @@
+use std::{convert::TryFrom, iter::Sum};
+
+use aoc_lib::{Error, Grid, Result, Scanner};
+
+const GRID_SIZE: usize = 1_000;
+
+pub enum Phrase {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+struct Instruction {
+    pub phrase: Phrase,
+    pub rect: (Coordinate, Coordinate),
+}
+
+pub struct Instructions(Vec<Instruction>);
+
+impl TryFrom<&str> for Instructions {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        Scanner::new(input)
+            .lines()
+            .map(|mut line| {
+                let phrase = line.one_of(vec![
+                    ("turn on", Phrase::TurnOn),
+                    ("turn off", Phrase::TurnOff),
+                    ("toggle", Phrase::Toggle),
+                ])?;
+                let (x1, y1, x2, y2) = aoc_lib::scan!(line, " {},{} through {},{}")?;
+                if x1 > x2 || y1 > y2 {
+                    return Err(line.error(format!(
+                        "rectangle {},{} through {},{} is reversed",
+                        x1, y1, x2, y2
+                    )));
+                }
+                if x2 >= GRID_SIZE || y2 >= GRID_SIZE {
+                    return Err(line.error(format!(
+                        "rectangle {},{} through {},{} does not fit in the {}x{} grid",
+                        x1, y1, x2, y2, GRID_SIZE, GRID_SIZE
+                    )));
+                }
+
+                Ok(Instruction {
+                    phrase,
+                    rect: (Coordinate { x: x1, y: y1 }, Coordinate { x: x2, y: y2 }),
+                })
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+impl Instructions {
+    pub fn sum<Result, Cell, Callback>(self, f: Callback) -> Result
+    where
+        Result: Sum<Result> + From<Cell>,
+        Cell: Default + Copy,
+        Callback: Fn(&Phrase, &mut Cell),
+    {
+        let mut grid = Grid::filled(GRID_SIZE, GRID_SIZE, Cell::default());
+
+        for ins in self.0 {
+            let (from, to) = ins.rect;
+            for row in grid.rect_mut(from.x..=to.x, from.y..=to.y) {
+                for cell in row {
+                    f(&ins.phrase, cell);
+                }
+            }
+        }
+
+        grid.iter().map(|t| Result::from(*t)).sum()
+    }
+}
@@ -0,0 +1,86 @@
+//! [Day 6: Probably a Fire Hazard](https://adventofcode.com/2015/day/6)
+
+pub mod domain;
+
+use std::convert::TryFrom;
+
+use aoc_lib::{Result, TryPart1, TryPart2};
+use domain::{Instructions, Phrase};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/06.txt"));
+
+#[derive(Default)]
+pub struct Day06;
+
+impl TryPart1 for Day06 {
+    type A = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        Ok(
+            Instructions::try_from(input)?.sum(|phrase, lit: &mut bool| {
+                *lit = match phrase {
+                    Phrase::TurnOn => true,
+                    Phrase::TurnOff => false,
+                    Phrase::Toggle => !*lit,
+                }
+            }),
+        )
+    }
+}
+
+impl TryPart2 for Day06 {
+    type B = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        Ok(
+            Instructions::try_from(input)?.sum(|phrase, brightness: &mut usize| match phrase {
+                Phrase::TurnOn => *brightness += 1,
+                Phrase::TurnOff => *brightness = brightness.checked_sub(1).unwrap_or(0),
+                Phrase::Toggle => *brightness += 2,
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day06, INPUT};
+    use aoc_lib::TryPart1;
+
+    aoc_lib::test_table! {
+        Day06;
+        part1_example: Part1 {
+            "turn on 0,0 through 999,999" => 1_000 * 1_000,
+            "toggle 0,0 through 999,999" => 1_000 * 1_000,
+            "turn on 499,499 through 500,500" => 4,
+        },
+        part2_example: Part2 {
+            "turn on 0,0 through 0,0" => 1,
+            "toggle 0,0 through 999,999" => 2_000_000,
+        },
+        part1_answer: Part1 { INPUT => 400410 },
+        part2_answer: Part2 { INPUT => 15343601 },
+    }
+
+    #[test]
+    fn part1_invalid() {
+        let message = |input| TryPart1::try_solve(&Day06, input).unwrap_err().message;
+
+        assert_eq!(
+            message("toggle 0,0 through 9,9\nturn 1,1 through 2,2"),
+            "line 2, column 1: expected one of `turn on`, `turn off`, `toggle`, found `turn`"
+        );
+        assert_eq!(
+            message("turn on 0,0 to 9,9"),
+            "line 1, column 12: expected ` through `, found ` to 9,9`"
+        );
+        assert_eq!(
+            message("turn on 5,5 through 1,9"),
+            "line 1, column 8: rectangle 5,5 through 1,9 is reversed"
+        );
+        assert_eq!(
+            message("toggle 0,0 through 999,1000"),
+            "line 1, column 7: rectangle 0,0 through 999,1000 does not fit in the 1000x1000 grid"
+        );
+    }
+}
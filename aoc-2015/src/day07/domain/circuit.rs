@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, convert::TryFrom};
+
+use aoc_lib::{Error, Result};
 
 use super::{
     gate::Gate,
@@ -11,32 +13,62 @@ use super::{
 pub struct Circuit(pub Vec<Instruction>);
 
 impl Circuit {
-    pub fn run(mut self) -> HashMap<WireIdentifier, Signal> {
+    /// Processes every instruction until all wires have a signal. Fails if a
+    /// wire has more than one signal provider or if some wires can never get
+    /// a signal, which would otherwise loop forever.
+    pub fn run(mut self) -> Result<HashMap<WireIdentifier, Signal>> {
         let mut wires = HashMap::new();
         while !self.0.is_empty() {
+            let pending = self.0.len();
+            let mut rewired = None;
+
             self.0
-                .retain(|instruction| !instruction.process(&mut wires).unwrap());
+                .retain(|instruction| match instruction.process(&mut wires) {
+                    Some(processed) => !processed,
+                    None => {
+                        rewired = Some(instruction.output_wire.clone());
+                        false
+                    }
+                });
+
+            if let Some(wire) = rewired {
+                return Err(Error::parse(format!(
+                    "wire {} has more than one signal provider",
+                    wire
+                )));
+            }
+            if self.0.len() == pending {
+                return Err(Error::parse(format!(
+                    "{} wire(s) can never get a signal",
+                    pending
+                )));
+            }
         }
-        wires
+        Ok(wires)
     }
 }
 
-impl From<&str> for Circuit {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Circuit {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
         let instrs = input
             .lines()
             .map(|line| {
+                let invalid = || Error::parse(format!("invalid instruction `{}`", line));
                 let mut tokens = line.split(' ').peekable();
 
                 // Here we're parsing the left side of the instruction a.k.a the SignalProvider. For
                 // example a Gate `x OR y`, a WireIdentifier `lx` or a number `123`.
-                let signal = match tokens.next().unwrap() {
+                let signal = match tokens.next().ok_or_else(invalid)? {
                     // Line looks like a NOT gate: `NOT x` -> y
-                    "NOT" => SignalProvider::Gate(Gate::NOT(Source::parse(tokens.next().unwrap()))),
+                    "NOT" => SignalProvider::Gate(Gate::NOT(Source::parse(
+                        tokens.next().ok_or_else(invalid)?,
+                    ))),
                     // We're `peeking` here since there is an edge case for `SignalProvider::Source -> WireIdentifier`. If we
                     // would have done `.next()` we would be at `->` and the code after `signal =
                     // match` block would panic.
-                    sig => match *tokens.peek().unwrap() {
+                    sig => match *tokens.peek().ok_or_else(invalid)? {
                         // Every arm of this match matches a binary operator, for example:
                         //    vvv____________________________^^^^^^^^^^^^^^^
                         // `x AND y -> z`.
@@ -46,19 +78,19 @@ impl From<&str> for Circuit {
                         "AND" => SignalProvider::Gate(Gate::AND(
                             Source::parse(sig),
                             // Skipping because of peek() in match.
-                            Source::parse(tokens.nth(1).unwrap()),
+                            Source::parse(tokens.nth(1).ok_or_else(invalid)?),
                         )),
                         "OR" => SignalProvider::Gate(Gate::OR(
                             Source::parse(sig),
-                            Source::parse(tokens.nth(1).unwrap()),
+                            Source::parse(tokens.nth(1).ok_or_else(invalid)?),
                         )),
                         "LSHIFT" => SignalProvider::Gate(Gate::LSHIFT(
                             Source::parse(sig),
-                            Source::parse(tokens.nth(1).unwrap()),
+                            Source::parse(tokens.nth(1).ok_or_else(invalid)?),
                         )),
                         "RSHIFT" => SignalProvider::Gate(Gate::RSHIFT(
                             Source::parse(sig),
-                            Source::parse(tokens.nth(1).unwrap()),
+                            Source::parse(tokens.nth(1).ok_or_else(invalid)?),
                         )),
                         // This is a SignalProvider which is a simple Source value: `123` -> x, or a
                         // wire identifier: `lx` -> y.
@@ -78,15 +110,15 @@ impl From<&str> for Circuit {
                 //
                 // Now skip the `->` token and get the `output_wire`.
                 //     ^^^^^^^^^^^^^_____vvvvvv
-                let output_wire = tokens.nth(1).unwrap().to_string();
+                let output_wire = tokens.nth(1).ok_or_else(invalid)?.to_string();
 
-                Instruction {
+                Ok(Instruction {
                     signal,
                     output_wire,
-                }
+                })
             })
-            .collect();
+            .collect::<Result<_>>()?;
 
-        Self(instrs)
+        Ok(Self(instrs))
     }
 }
@@ -8,6 +8,7 @@ use super::{
 /// A Gate is a bitwise operation on 1 on or more [Source](./enum.Source.html)'s. Possible bitwise
 /// operations include: AND, OR, LSHIFT, RSHIFT or NOT.
 #[derive(Clone, Debug)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Gate {
     AND(Source, Source),
     OR(Source, Source),
@@ -23,9 +24,7 @@ impl Gate {
     ///
     /// # Examples
     /// ```
-    /// # #[macro_use]
     /// # use aoc_2015::day07::{Gate, Source};
-    /// # use std::collections::HashMap;
     /// # fn main() {
     /// let wires = [("x".into(), 1)].iter().cloned().collect();
     ///
@@ -38,21 +37,21 @@ impl Gate {
     ///            None);
     /// # }
     /// ```
-    pub(super) fn output(&self, wires: &HashMap<WireIdentifier, Signal>) -> Option<Signal> {
+    pub fn output(&self, wires: &HashMap<WireIdentifier, Signal>) -> Option<Signal> {
         match &self {
             Gate::AND(s1, s2) => s1
-                .signal(&wires)
-                .and_then(|v1| s2.signal(&wires).map(|v2| v1 & v2)),
+                .signal(wires)
+                .and_then(|v1| s2.signal(wires).map(|v2| v1 & v2)),
             Gate::OR(s1, s2) => s1
-                .signal(&wires)
-                .and_then(|v1| s2.signal(&wires).map(|v2| v1 | v2)),
+                .signal(wires)
+                .and_then(|v1| s2.signal(wires).map(|v2| v1 | v2)),
             Gate::LSHIFT(s1, s2) => s1
-                .signal(&wires)
-                .and_then(|v1| s2.signal(&wires).map(|v2| v1 << v2)),
+                .signal(wires)
+                .and_then(|v1| s2.signal(wires).map(|v2| v1 << v2)),
             Gate::RSHIFT(s1, s2) => s1
-                .signal(&wires)
-                .and_then(|v1| s2.signal(&wires).map(|v2| v1 >> v2)),
-            Gate::NOT(s1) => s1.signal(&wires).map(|v1| !v1),
+                .signal(wires)
+                .and_then(|v1| s2.signal(wires).map(|v2| v1 >> v2)),
+            Gate::NOT(s1) => s1.signal(wires).map(|v1| !v1),
         }
     }
 }
@@ -19,15 +19,15 @@ impl Instruction {
     /// output_wire. If the instruction could be processed it will return Some(true) otherwise
     /// Some(false). Returns none if wire already has a signal since this is not allowed by the
     /// puzzle instructions.
-    pub(super) fn process(&self, wires: &mut HashMap<WireIdentifier, Signal>) -> Option<bool> {
+    pub fn process(&self, wires: &mut HashMap<WireIdentifier, Signal>) -> Option<bool> {
         if wires.contains_key(&self.output_wire) {
             // A wire may only get a signal by 1 signal provider only.
             return None;
         }
 
         if let Some(signal) = match &self.signal {
-            SignalProvider::Source(s) => s.signal(&wires),
-            SignalProvider::Gate(gate) => gate.output(&wires),
+            SignalProvider::Source(s) => s.signal(wires),
+            SignalProvider::Gate(gate) => gate.output(wires),
         } {
             wires.insert(self.output_wire.clone(), signal);
             Some(true)
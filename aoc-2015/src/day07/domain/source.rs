@@ -13,7 +13,7 @@ pub enum Source {
 
 impl Source {
     /// Returns the signal of a Source.
-    pub(super) fn signal(&self, wires: &HashMap<WireIdentifier, Signal>) -> Option<Signal> {
+    pub fn signal(&self, wires: &HashMap<WireIdentifier, Signal>) -> Option<Signal> {
         match &self {
             Source::Value(signal) => Some(*signal),
             Source::Wire(wire) => wires.get(wire).copied(),
@@ -23,7 +23,7 @@ impl Source {
     /// A helper function that tries to parse the given string to a Source::Value. If this does not
     /// succeed it is assumed that the given string is a wire identifier and Source::Wire is
     /// returned.
-    pub(super) fn parse(wire_or_value: &str) -> Source {
+    pub fn parse(wire_or_value: &str) -> Source {
         wire_or_value
             .parse()
             .map(Source::Value)
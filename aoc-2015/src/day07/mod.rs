@@ -0,0 +1,105 @@
+//! [Day 7: Some Assembly Required](https://adventofcode.com/2015/day/7)
+
+pub mod domain;
+
+use std::convert::TryFrom;
+
+use aoc_lib::{Error, Result, TryPart1, TryPart2};
+use domain::signal::SignalProvider;
+pub use domain::{circuit::Circuit, gate::Gate, source::Source};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/07.txt"));
+
+#[derive(Default)]
+pub struct Day07;
+
+fn wire_a(circuit: Circuit) -> Result<u16> {
+    circuit
+        .run()?
+        .get("a")
+        .copied()
+        .ok_or_else(|| Error::no_answer("wire a does not exist"))
+}
+
+impl TryPart1 for Day07 {
+    type A = u16;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        wire_a(Circuit::try_from(input)?)
+    }
+}
+
+impl TryPart2 for Day07 {
+    type B = u16;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        let mut circuit = Circuit::try_from(input)?;
+        let signal_a = wire_a(circuit.clone())?;
+
+        circuit
+            .0
+            .iter_mut()
+            .find(|x| x.output_wire == "b")
+            .ok_or_else(|| Error::no_answer("could not find wire b"))?
+            .signal = SignalProvider::Source(Source::Value(signal_a));
+
+        wire_a(circuit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::domain::circuit::Circuit;
+
+    use super::{Day07, INPUT};
+    use aoc_lib::{ErrorKind, TryPart1};
+
+    aoc_lib::test_table! {
+        Day07;
+        part1_answer: Part1 { INPUT => 46065 },
+        part2_answer: Part2 { INPUT => 14134 },
+    }
+
+    #[test]
+    fn part1_example() {
+        let simple_circuit = "\
+            123 -> x\n\
+            456 -> y\n\
+            x AND y -> d\n\
+            x OR y -> e\n\
+            x LSHIFT 2 -> f\n\
+            y RSHIFT 2 -> g\n\
+            NOT x -> h\n\
+            NOT y -> i";
+
+        assert_eq!(
+            Circuit::try_from(simple_circuit).and_then(Circuit::run),
+            Ok([
+                ("d".into(), 72),
+                ("e".into(), 507),
+                ("f".into(), 492),
+                ("g".into(), 114),
+                ("h".into(), 65412),
+                ("i".into(), 65079),
+                ("x".into(), 123),
+                ("y".into(), 456),
+            ]
+            .iter()
+            .cloned()
+            .collect())
+        );
+    }
+
+    #[test]
+    fn part1_invalid() {
+        let kind = |input| TryPart1::try_solve(&Day07, input).unwrap_err().kind;
+
+        assert_eq!(kind("123 ->"), ErrorKind::Parse);
+        assert_eq!(kind("x AND"), ErrorKind::Parse);
+        assert_eq!(kind("x -> a\ny -> x"), ErrorKind::Parse);
+        assert_eq!(kind("1 -> x\n2 -> x"), ErrorKind::Parse);
+        assert_eq!(kind("1 -> b"), ErrorKind::NoAnswer);
+    }
+}
@@ -0,0 +1,62 @@
+//! [Day 8: Matchsticks](https://adventofcode.com/2015/day/8)
+
+use aoc_lib::{Part1, Part2};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/08.txt"));
+
+#[derive(Default)]
+pub struct Day08;
+
+impl Part1 for Day08 {
+    type A = usize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        input
+            .lines()
+            .map(|line| line[1..line.len() - 1].chars())
+            .try_fold(0, |sum, mut it| {
+                let mut line_sum = 2;
+                while it.any(|c| c == '\\') {
+                    line_sum += match it.next()? {
+                        'x' => 3,
+                        _ => 1,
+                    }
+                }
+                Some(sum + line_sum)
+            })
+            .unwrap()
+    }
+}
+
+impl Part2 for Day08 {
+    type B = usize;
+
+    fn solve(&self, input: &str) -> Self::B {
+        input.lines().fold(0, |sum, line| {
+            sum + line.escape_default().count() - line.len() + 2
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day08, INPUT};
+
+    aoc_lib::test_table! {
+        Day08;
+        part1_example: Part1 {
+            r#""""# => 2,
+            r#""abc""# => 2,
+            r#""aaa\"aaa""# => 3,
+            r#""\x27""# => 5,
+        },
+        part2_example: Part2 {
+            r#""""# => 4,
+            r#""abc""# => 4,
+            r#""aaa\"aaa""# => 6,
+            r#""\x27""# => 5,
+        },
+        part1_answer: Part1 { INPUT => 1371 },
+        part2_answer: Part2 { INPUT => 2117 },
+    }
+}
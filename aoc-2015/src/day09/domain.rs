@@ -0,0 +1,121 @@
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    iter::FromIterator,
+};
+
+use aoc_lib::{Error, Result, Scanner};
+use itertools::{Itertools, MinMaxResult};
+
+pub struct Route<'r> {
+    from: &'r str,
+    to: &'r str,
+    dist: usize,
+}
+
+impl<'r> TryFrom<Scanner<'r>> for Route<'r> {
+    type Error = Error;
+
+    fn try_from(line: Scanner<'r>) -> Result<Self> {
+        let (from, to, dist) = aoc_lib::scan!(line, "{} to {} = {}")?;
+        Ok(Route { from, to, dist })
+    }
+}
+
+pub struct RouteMap<'r>(pub Vec<Route<'r>>);
+
+impl<'r> TryFrom<&'r str> for RouteMap<'r> {
+    type Error = Error;
+
+    fn try_from(input: &'r str) -> Result<Self> {
+        Scanner::new(input)
+            .lines()
+            .map(Route::try_from)
+            .collect::<Result<_>>()
+            .map(RouteMap)
+    }
+}
+
+impl<'r> FromIterator<Route<'r>> for MinMaxResult<usize> {
+    fn from_iter<T: IntoIterator<Item = Route<'r>>>(iter: T) -> Self {
+        let mut cities = HashSet::new();
+        let mut dists = HashMap::new();
+
+        for route in iter {
+            cities.insert(route.from);
+            cities.insert(route.to);
+            dists.insert((route.from, route.to), route.dist);
+            dists.insert((route.to, route.from), route.dist);
+        }
+
+        cities
+            .iter()
+            .permutations(cities.len())
+            // Routes which pass two cities without a distance between them
+            // are skipped
+            .filter_map(|route| {
+                route
+                    .as_slice()
+                    .windows(2)
+                    .map(|cities| dists.get(&(cities[0], cities[1])))
+                    .sum::<Option<usize>>()
+            })
+            .minmax()
+    }
+}
+
+impl RouteMap<'_> {
+    /// Finds the shortest and longest route which visits every city exactly
+    /// once with the Held-Karp algorithm, which keeps the shortest and longest
+    /// distance of every set of visited cities per city the route ends in
+    /// instead of trying every order of the cities.
+    pub fn held_karp(&self) -> Option<(usize, usize)> {
+        let mut cities = Vec::new();
+        for route in &self.0 {
+            for city in [route.from, route.to] {
+                if !cities.contains(&city) {
+                    cities.push(city);
+                }
+            }
+        }
+
+        let n = cities.len();
+        let index = |city| cities.iter().position(|&c| c == city).unwrap();
+        let mut dists = vec![vec![None; n]; n];
+        for route in &self.0 {
+            let (from, to) = (index(route.from), index(route.to));
+            dists[from][to] = Some(route.dist);
+            dists[to][from] = Some(route.dist);
+        }
+
+        // The shortest and longest distance of the routes which visit the
+        // cities of the set and end in a city, indexed by `set * n + city`.
+        let mut routes: Vec<Option<(usize, usize)>> = vec![None; n << n];
+        for city in 0..n {
+            routes[(1 << city) * n + city] = Some((0, 0));
+        }
+
+        for set in 1..1 << n {
+            for last in (0..n).filter(|last| set & 1 << last != 0) {
+                let Some((shortest, longest)) = routes[set * n + last] else {
+                    continue;
+                };
+                for next in (0..n).filter(|next| set & 1 << next == 0) {
+                    let Some(dist) = dists[last][next] else {
+                        continue;
+                    };
+                    let route = &mut routes[(set | 1 << next) * n + next];
+                    *route = Some(match *route {
+                        Some((min, max)) => (min.min(shortest + dist), max.max(longest + dist)),
+                        None => (shortest + dist, longest + dist),
+                    });
+                }
+            }
+        }
+
+        let all = (1 << n) - 1;
+        (0..n)
+            .filter_map(|last| routes[all * n + last])
+            .reduce(|(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)))
+    }
+}
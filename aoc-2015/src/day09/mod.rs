@@ -0,0 +1,117 @@
+//! [Day 9: All in a Single Night](https://adventofcode.com/2015/day/9)
+
+pub mod domain;
+
+use std::convert::TryFrom;
+
+use aoc_lib::{Error, Result, TryPart1, TryPart2};
+use domain::RouteMap;
+use itertools::MinMaxResult;
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/09.txt"));
+
+#[derive(Default)]
+pub struct Day09;
+
+impl TryPart1 for Day09 {
+    type A = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        RouteMap::try_from(input)?
+            .0
+            .into_iter()
+            .collect::<MinMaxResult<_>>()
+            .into_option()
+            .map(|mm| mm.0)
+            .ok_or_else(|| Error::no_answer("could not find shortest route"))
+    }
+}
+
+impl TryPart2 for Day09 {
+    type B = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        RouteMap::try_from(input)?
+            .0
+            .into_iter()
+            .collect::<MinMaxResult<_>>()
+            .into_option()
+            .map(|mm| mm.1)
+            .ok_or_else(|| Error::no_answer("could not find longest route"))
+    }
+}
+
+/// Finds the routes with [RouteMap::held_karp] instead of trying every order
+/// of the cities, registered as an alternative of [Day09].
+#[derive(Default)]
+pub struct HeldKarp;
+
+impl TryPart1 for HeldKarp {
+    type A = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        RouteMap::try_from(input)?
+            .held_karp()
+            .map(|routes| routes.0)
+            .ok_or_else(|| Error::no_answer("could not find shortest route"))
+    }
+}
+
+impl TryPart2 for HeldKarp {
+    type B = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        RouteMap::try_from(input)?
+            .held_karp()
+            .map(|routes| routes.1)
+            .ok_or_else(|| Error::no_answer("could not find longest route"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day09, HeldKarp, INPUT};
+    use aoc_lib::{TryPart1, TryPart2};
+
+    aoc_lib::test_table! {
+        Day09;
+        part1_example: Part1 { aoc_lib::example!("09.txt") => 605 },
+        part2_example: Part2 { aoc_lib::example!("09.txt") => 982 },
+        part1_answer: Part1 { INPUT => 117 },
+        part2_answer: Part2 { INPUT => 909 },
+    }
+
+    aoc_lib::test_table! {
+        HeldKarp;
+        held_karp_part1_example: Part1 { aoc_lib::example!("09.txt") => 605 },
+        held_karp_part2_example: Part2 { aoc_lib::example!("09.txt") => 982 },
+        held_karp_part1_answer: Part1 { INPUT => 117 },
+        held_karp_part2_answer: Part2 { INPUT => 909 },
+    }
+
+    #[test]
+    fn part1_invalid() {
+        let err = TryPart1::try_solve(&Day09, "London to Dublin = 464\nDublin - Belfast = 141");
+        assert_eq!(
+            err.unwrap_err().message,
+            "line 2, column 7: expected ` to `, found ` - Belfast = 141`"
+        );
+    }
+
+    #[test]
+    fn disconnected_cities() {
+        let input = "A to B = 1\nC to D = 2";
+        for err in [
+            TryPart1::try_solve(&Day09, input).unwrap_err(),
+            TryPart1::try_solve(&HeldKarp, input).unwrap_err(),
+        ] {
+            assert_eq!(err.message, "could not find shortest route");
+        }
+        for err in [
+            TryPart2::try_solve(&Day09, input).unwrap_err(),
+            TryPart2::try_solve(&HeldKarp, input).unwrap_err(),
+        ] {
+            assert_eq!(err.message, "could not find longest route");
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! [Day 10: Elves Look, Elves Say](https://adventofcode.com/2015/day/10)
+
+pub mod domain;
+
+use aoc_lib::{Part1, Part2};
+use domain::LookAndSay;
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/10.txt"));
+
+#[derive(Clone)]
+pub struct Day10 {
+    pub part1_rounds: usize,
+    pub part2_rounds: usize,
+}
+
+impl Default for Day10 {
+    fn default() -> Self {
+        Self {
+            part1_rounds: 40,
+            part2_rounds: 50,
+        }
+    }
+}
+
+aoc_lib::params! {
+    Day10 {
+        /// Times the process is applied in part 1.
+        part1_rounds: usize,
+        /// Times the process is applied in part 2.
+        part2_rounds: usize,
+    }
+}
+
+impl Part1 for Day10 {
+    type A = usize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        LookAndSay::new(input)
+            .take(self.part1_rounds)
+            .last()
+            .map_or(input.len(), |sequence| sequence.len())
+    }
+}
+
+impl Part2 for Day10 {
+    type B = usize;
+
+    fn solve(&self, input: &str) -> Self::B {
+        LookAndSay::new(input)
+            .take(self.part2_rounds)
+            .last()
+            .map_or(input.len(), |sequence| sequence.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day10, INPUT};
+
+    aoc_lib::test_table! {
+        Day10 { part1_rounds: 5, ..Day10::default() };
+        part1_example: Part1 { "1" => "312211".len() },
+    }
+
+    aoc_lib::test_table! {
+        Day10::default();
+        part1_answer: Part1 { INPUT => 360154 },
+        part2_answer: Part2 { INPUT => 5103798 },
+    }
+}
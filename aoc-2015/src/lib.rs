@@ -0,0 +1,39 @@
+//! Solutions for the [Advent of Code 2015](https://adventofcode.com/2015) puzzles.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+
+use aoc_lib::{Implementation, Registry};
+
+pub const YEAR: u16 = 2015;
+
+/// Registers every solution of this year.
+pub fn register(registry: &mut Registry) {
+    registry.set_dir(YEAR, env!("CARGO_MANIFEST_DIR"));
+    registry.register(YEAR, 1, day01::INPUT, day01::Day01);
+    registry.register(YEAR, 2, day02::INPUT, day02::Day02);
+    registry.register(YEAR, 3, day03::INPUT, day03::Day03);
+    registry.register(YEAR, 4, day04::INPUT, day04::Day04);
+    registry.register(YEAR, 5, day05::INPUT, day05::Day05);
+    registry.register(YEAR, 6, day06::INPUT, day06::Day06);
+    registry.register(YEAR, 7, day07::INPUT, day07::Day07);
+    registry.register(YEAR, 8, day08::INPUT, day08::Day08);
+    registry.register(YEAR, 9, day09::INPUT, day09::Day09);
+    registry.register_alternative(YEAR, 9, Implementation::part1("held_karp", day09::HeldKarp));
+    registry.register_alternative(YEAR, 9, Implementation::part2("held_karp", day09::HeldKarp));
+    registry.register_with_params(YEAR, 10, day10::INPUT, day10::Day10::default());
+}
+
+/// Runs and prints the answers of a single day of this year, see
+/// [aoc_lib::solve_print](aoc_lib::solve_print).
+pub fn solve_print(day: u8) {
+    aoc_lib::solve_print(register, YEAR, day);
+}
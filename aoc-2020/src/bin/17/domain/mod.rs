@@ -1,3 +0,0 @@
-pub mod pocket;
-pub mod point3;
-pub mod point4;
@@ -1,6 +1,7 @@
 use std::collections::VecDeque;
 
 #[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
 pub struct XMAS {
     xs: VecDeque<usize>,
 }
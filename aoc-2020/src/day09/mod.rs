@@ -0,0 +1,117 @@
+//! [Day 9: Encoding Error](https://adventofcode.com/2020/day/9)
+
+pub mod domain;
+
+use aoc_lib::{Error, Result, Solution};
+use domain::XMAS;
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/09.txt"));
+
+#[derive(Clone)]
+pub struct Day09 {
+    pub preamble_count: usize,
+}
+
+aoc_lib::params! {
+    Day09 {
+        /// Numbers before the first number which is checked.
+        preamble_count: usize,
+    }
+}
+
+impl Default for Day09 {
+    fn default() -> Self {
+        Self { preamble_count: 25 }
+    }
+}
+
+impl Solution for Day09 {
+    type Parsed = Vec<usize>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let numbers = input
+            .lines()
+            .map(|line| Ok(line.parse()?))
+            .collect::<Result<Vec<_>>>()?;
+
+        if numbers.len() <= self.preamble_count {
+            return Err(Error::parse(format!(
+                "expected more than {} numbers",
+                self.preamble_count
+            )));
+        }
+        Ok(numbers)
+    }
+
+    fn part1(&self, numbers: &Self::Parsed) -> Result<Self::A> {
+        let preamble = &numbers[..self.preamble_count];
+        let mut cypher = XMAS::new(preamble);
+
+        for x in &numbers[self.preamble_count..] {
+            let is_valid = cypher.next(*x);
+            if !is_valid {
+                return Ok(*x);
+            }
+        }
+
+        Err(Error::no_answer("every number is valid"))
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Self::B> {
+        let target = self.part1(parsed)?;
+
+        for (start_idx, val) in parsed.iter().enumerate() {
+            let mut processed = 0;
+            let mut sum = *val;
+
+            let mut xs = parsed[start_idx + 1..].iter();
+            while sum < target {
+                match xs.next() {
+                    Some(v) => {
+                        sum += v;
+                        processed += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            if processed >= 2 && sum == target {
+                let range = &parsed[start_idx..=start_idx + processed];
+                let min = range.iter().min().expect("could not get min");
+                let max = range.iter().max().expect("could not get max");
+                return Ok(min + max);
+            }
+        }
+
+        Err(Error::no_answer(format!(
+            "no contiguous set of numbers sums to {}",
+            target
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day09, INPUT};
+    use aoc_lib::Solution;
+
+    aoc_lib::test_table! {
+        Day09 { preamble_count: 5 };
+        part1_example: Part1 { aoc_lib::example!("09.txt") => 127 },
+        part2_example: Part2 { aoc_lib::example!("09.txt") => 62 },
+    }
+
+    aoc_lib::test_table! {
+        Day09::default();
+        part1_answer: Part1 { INPUT => 21806024 },
+        part2_answer: Part2 { INPUT => 2986195 },
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Day09::default().parse("1\n2\nthree").is_err());
+        assert!(Day09::default().parse("1\n2\n3").is_err());
+    }
+}
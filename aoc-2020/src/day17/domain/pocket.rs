@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use aoc_lib::{Automaton, Grid, LifeLike, Neighbours, Point, Sparse};
+
+/// The neighbours of a cube.
+type Nbors<const N: usize> = fn(Point<N>) -> Neighbours<N>;
+
+/// Pocket consists of **active** cubes in `N` dimensions.
+#[derive(Debug)]
+pub struct Pocket<const N: usize>(Automaton<Sparse<Point<N>, Nbors<N>, LifeLike>>);
+
+/// Places the initial 2D slice at the origin of every other dimension.
+impl<const N: usize> From<&Grid<bool>> for Pocket<N> {
+    fn from(slice: &Grid<bool>) -> Self {
+        let cubes = slice
+            .positions()
+            .filter(|(_, &active)| active)
+            .map(|((x, y), _)| {
+                let mut cube = Point::ORIGIN;
+                cube[0] = x as isize;
+                cube[1] = y as isize;
+                cube
+            });
+
+        /*
+         * During a cycle, all cubes simultaneously change their state
+         * according to the following rules:
+
+         * - If a cube is active and exactly 2 or 3 of its neighbors are also
+         *   active, the cube remains active. Otherwise, the cube becomes
+         *   inactive.
+         * - If a cube is inactive but exactly 3 of its neighbors are
+         *   active, the cube becomes active. Otherwise, the cube remains
+         *   inactive.
+        */
+        let rule = LifeLike::new(&[3], &[2, 3]);
+
+        Self(Automaton::sparse(cubes, Point::neighbours, rule))
+    }
+}
+
+impl<const N: usize> Pocket<N> {
+    pub fn cubes(&self) -> &HashSet<Point<N>> {
+        self.0.backend().cells()
+    }
+
+    pub fn size(&self) -> usize {
+        self.0.population()
+    }
+
+    /// Runs the given amount of cycles.
+    pub fn run(&mut self, cycles: usize) {
+        self.0.run(cycles);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_slice_works() {
+        let slice = Grid::parse(".#.\n..#\n###", |c| Some(c == '#')).unwrap();
+        let pocket = Pocket::<3>::from(&slice);
+
+        assert_eq!(
+            pocket.cubes(),
+            &vec![
+                Point([1, 0, 0]),
+                Point([2, 1, 0]),
+                Point([0, 2, 0]),
+                Point([1, 2, 0]),
+                Point([2, 2, 0]),
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+}
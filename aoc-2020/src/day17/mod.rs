@@ -0,0 +1,119 @@
+//! [Day 17: Conway Cubes](https://adventofcode.com/2020/day/17)
+
+pub mod domain;
+
+use aoc_lib::{Error, Grid, Result, Solution};
+pub use domain::pocket::Pocket;
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/17.txt"));
+
+#[derive(Clone)]
+pub struct Day17 {
+    pub cycles: usize,
+    pub part1_dimensions: usize,
+    pub part2_dimensions: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Self {
+            cycles: 6,
+            part1_dimensions: 3,
+            part2_dimensions: 4,
+        }
+    }
+}
+
+aoc_lib::params! {
+    Day17 {
+        /// Cycles of the boot process.
+        cycles: usize,
+        /// Dimensions of the pocket in part 1, from 2 to 8.
+        part1_dimensions: usize,
+        /// Dimensions of the pocket in part 2, from 2 to 8.
+        part2_dimensions: usize,
+    }
+}
+
+impl Day17 {
+    /// Returns the amount of active cubes after booting a pocket with the
+    /// given amount of dimensions.
+    fn boot(&self, slice: &Grid<bool>, dimensions: usize) -> Result<usize> {
+        fn boot<const N: usize>(slice: &Grid<bool>, cycles: usize) -> usize {
+            let mut pocket = Pocket::<N>::from(slice);
+            pocket.run(cycles);
+            pocket.size()
+        }
+
+        match dimensions {
+            2 => Ok(boot::<2>(slice, self.cycles)),
+            3 => Ok(boot::<3>(slice, self.cycles)),
+            4 => Ok(boot::<4>(slice, self.cycles)),
+            5 => Ok(boot::<5>(slice, self.cycles)),
+            6 => Ok(boot::<6>(slice, self.cycles)),
+            7 => Ok(boot::<7>(slice, self.cycles)),
+            8 => Ok(boot::<8>(slice, self.cycles)),
+            _ => Err(Error::param(format!(
+                "a pocket cannot have {} dimensions, expected 2 to 8",
+                dimensions
+            ))),
+        }
+    }
+}
+
+impl Solution for Day17 {
+    type Parsed = Grid<bool>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Grid::parse(input, |state| match state {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    fn part1(&self, slice: &Self::Parsed) -> Result<Self::A> {
+        self.boot(slice, self.part1_dimensions)
+    }
+
+    fn part2(&self, slice: &Self::Parsed) -> Result<Self::B> {
+        self.boot(slice, self.part2_dimensions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day17, INPUT};
+    use aoc_lib::Solution;
+
+    const EXAMPLE_INPUT: &str = "\
+        .#.\n\
+        ..#\n\
+        ###";
+
+    aoc_lib::test_table! {
+        Day17::default();
+        part1_example: Part1 { EXAMPLE_INPUT => 112 },
+        part2_example: Part2 { EXAMPLE_INPUT => 848 },
+        part1_answer: Part1 { INPUT => 230 },
+        part2_answer: Part2 { INPUT => 1600 },
+    }
+
+    aoc_lib::test_table! {
+        Day17 { cycles: 1, part1_dimensions: 2, part2_dimensions: 5 };
+        example_other_dimensions: Part1 { EXAMPLE_INPUT => 5 },
+    }
+
+    #[test]
+    fn invalid_dimensions() {
+        let day = Day17 {
+            part1_dimensions: 9,
+            ..Day17::default()
+        };
+        let slice = day.parse(EXAMPLE_INPUT).unwrap();
+        assert!(day.part1(&slice).is_err());
+        assert!(day.parse(".#\n?#").is_err());
+    }
+}
@@ -24,8 +24,8 @@ fn eval_helper(eq: &Equation) -> u64 {
     match eq {
         Equation::Number(val) => *val,
         Equation::Expr(lhs, op, rhs) => {
-            let lhs = eval_helper(&lhs);
-            let rhs = eval_helper(&rhs);
+            let lhs = eval_helper(lhs);
+            let rhs = eval_helper(rhs);
 
             match op {
                 Operator::Add => lhs + rhs,
@@ -3,7 +3,7 @@ use std::{fmt::Display, iter::Peekable};
 use super::tokenizer::Token;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub(super) enum Operator {
+pub enum Operator {
     Add,
     Multiply,
 }
@@ -18,7 +18,7 @@ impl Display for Operator {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub(super) enum Equation {
+pub enum Equation {
     Number(u64),
     Expr(Box<Equation>, Operator, Box<Equation>),
     Group(Box<Equation>),
@@ -35,7 +35,7 @@ impl Display for Equation {
 }
 
 impl Equation {
-    pub(super) fn new_expr(lhs: Self, op: Operator, rhs: Self) -> Self {
+    pub fn new_expr(lhs: Self, op: Operator, rhs: Self) -> Self {
         Self::Expr(Box::new(lhs), op, Box::new(rhs))
     }
 
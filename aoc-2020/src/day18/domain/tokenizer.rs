@@ -1,5 +1,5 @@
 #[derive(Debug, PartialEq, Clone, Copy)]
-pub(super) enum Token {
+pub enum Token {
     Number(u64),
     Plus,
     Minus,
@@ -7,7 +7,7 @@ pub(super) enum Token {
     RParen,
 }
 
-pub(super) fn tokenize(s: &str) -> Vec<Token> {
+pub fn tokenize(s: &str) -> Vec<Token> {
     let mut tokens = vec![];
     let mut chars = s.chars().filter(|c| !c.is_whitespace()).peekable();
 
@@ -17,11 +17,11 @@ pub(super) fn tokenize(s: &str) -> Vec<Token> {
             '*' => Token::Minus,
             '(' => Token::LParen,
             ')' => Token::RParen,
-            n if n.is_digit(10) => {
+            n if n.is_ascii_digit() => {
                 let mut s = n.to_digit(10).unwrap() as u64;
                 loop {
                     match chars.peek() {
-                        Some(n) if n.is_digit(10) => {
+                        Some(n) if n.is_ascii_digit() => {
                             s *= 10;
                             s += chars.next().unwrap().to_digit(10).unwrap() as u64;
                         }
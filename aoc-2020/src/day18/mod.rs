@@ -0,0 +1,51 @@
+//! [Day 18: Operation Order](https://adventofcode.com/2020/day/18)
+
+pub mod domain;
+
+use aoc_lib::{Part1, Part2};
+use domain::{eval, eval2};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/18.txt"));
+
+#[derive(Default)]
+pub struct Day18;
+
+impl Part1 for Day18 {
+    type A = u64;
+
+    fn solve(&self, input: &str) -> Self::A {
+        input.lines().map(eval).sum()
+    }
+}
+
+impl Part2 for Day18 {
+    type B = u64;
+
+    fn solve(&self, input: &str) -> Self::B {
+        input.lines().map(eval2).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day18, INPUT};
+
+    aoc_lib::test_table! {
+        Day18;
+        part1_example: Part1 {
+            "2 * 3 + (4 * 5)" => 26,
+            "5 + (8 * 3 + 9 + 3 * 4 * 3)" => 437,
+            "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))" => 12240,
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2" => 13632,
+        },
+        part2_example: Part2 {
+            "1 + 2 * 3 + 4 * 5 + 6" => 231,
+            "2 * 3 + (4 * 5)" => 46,
+            "5 + (8 * 3 + 9 + 3 * 4 * 3)" => 1445,
+            "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))" => 669060,
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2" => 23340,
+        },
+        part1_answer: Part1 { INPUT => 36382392389406u64 },
+        part2_answer: Part2 { INPUT => 381107029777968u64 },
+    }
+}
@@ -1,5 +1,5 @@
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub(crate) enum CardinalDir {
+pub enum CardinalDir {
     North,
     East,
     South,
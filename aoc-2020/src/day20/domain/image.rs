@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, convert::TryFrom};
+
+use aoc_lib::{Error, Grid, Result};
 
 use super::{
     direction::CardinalDir,
@@ -6,10 +8,10 @@ use super::{
     tile::{Tile, TileCell, Tiles, O, TILE_SIZE, X},
 };
 
-pub(crate) const SEA_MONSTER_X_COUNT: usize = 15;
-pub(crate) const SEA_MONSTER_HEIGHT: usize = 3;
-pub(crate) const SEA_MONSTER_WIDTH: usize = 20;
-pub(crate) const SEA_MONSTER: [[TileCell; SEA_MONSTER_WIDTH]; SEA_MONSTER_HEIGHT] = {
+pub const SEA_MONSTER_X_COUNT: usize = 15;
+pub const SEA_MONSTER_HEIGHT: usize = 3;
+pub const SEA_MONSTER_WIDTH: usize = 20;
+pub const SEA_MONSTER: [[TileCell; SEA_MONSTER_WIDTH]; SEA_MONSTER_HEIGHT] = {
     [
         // The monster:
         // |                  # |
@@ -22,36 +24,32 @@ pub(crate) const SEA_MONSTER: [[TileCell; SEA_MONSTER_WIDTH]; SEA_MONSTER_HEIGHT
 };
 
 #[derive(Debug, Default, Clone)]
-pub(crate) struct Image(pub(crate) Vec<Vec<TileCell>>);
+pub struct Image(pub Grid<TileCell>);
 
 impl Orientable for Image {
     fn flip(&mut self) {
-        self.0.reverse();
+        self.0.flip_vertical();
     }
 
     fn rotate(&mut self) {
-        let mut rotated: Vec<Vec<TileCell>> = Default::default();
-        let n = self.height();
-        for i in 0..n {
-            rotated.push(vec![Default::default(); n]);
-            for j in 0..n {
-                rotated[i][j] = self.0[n - j - 1][i];
-            }
-        }
-
-        self.0 = rotated;
+        self.0.rotate_right();
     }
 }
 
 impl Image {
-    fn line_up_tiles(mut tiles: Tiles) -> Vec<Vec<Tile>> {
+    fn line_up_tiles(mut tiles: Tiles) -> Result<Vec<Vec<Tile>>> {
         let mut image_pos_tiles: HashMap<(isize, isize), Tile> = Default::default();
         let mut q: Vec<((isize, isize), Tile)> = Default::default();
 
         {
-            let start = *tiles.0.iter().next().unwrap();
+            let start = tiles
+                .0
+                .iter()
+                .next()
+                .cloned()
+                .ok_or_else(|| Error::parse("there are no tiles"))?;
             tiles.0.remove(&start);
-            image_pos_tiles.insert((0, 0), start);
+            image_pos_tiles.insert((0, 0), start.clone());
             q.push(((0, 0), start));
         }
 
@@ -59,8 +57,8 @@ impl Image {
         let mut ver_pos = (0, 0); // low, high
         while let Some((coord, tile)) = q.pop() {
             let mut found = vec![];
-            for other in &tiles.0 {
-                for orient in other.orientations() {
+            for other in tiles.0.iter().filter(|other| tile.shares_border(other)) {
+                for orient in other.clone().orientations() {
                     if let Some(dir) = tile.stitch_to(&orient) {
                         let d = match dir {
                             CardinalDir::North => (0, 1),
@@ -76,9 +74,9 @@ impl Image {
                         ver_pos.0 = ver_pos.0.min(orient_pos.1);
                         ver_pos.1 = ver_pos.1.max(orient_pos.1);
 
-                        image_pos_tiles.insert(orient_pos, orient);
+                        image_pos_tiles.insert(orient_pos, orient.clone());
                         q.push((orient_pos, orient));
-                        found.push(*other);
+                        found.push(other.clone());
                         break;
                     }
                 }
@@ -92,30 +90,35 @@ impl Image {
         (ver_pos.0..=ver_pos.1)
             .map(|y| {
                 (hor_pos.0..=hor_pos.1)
-                    .map(|x| *image_pos_tiles.get(&(x, y)).unwrap())
+                    .map(|x| {
+                        image_pos_tiles
+                            .get(&(x, y))
+                            .cloned()
+                            .ok_or_else(|| Error::parse("tiles do not form a rectangular image"))
+                    })
                     .collect()
             })
             .collect()
     }
 
-    pub(crate) fn width(&self) -> usize {
-        self.0[0].len()
-    }
-
-    pub(crate) fn height(&self) -> usize {
-        self.0.len()
-    }
+    pub fn sea_monsters(&self) -> usize {
+        let (Some(last_y), Some(last_x)) = (
+            self.0.height().checked_sub(SEA_MONSTER_HEIGHT),
+            self.0.width().checked_sub(SEA_MONSTER_WIDTH),
+        ) else {
+            // The image is too small to fit a sea monster
+            return 0;
+        };
 
-    pub(crate) fn sea_monsters(&self) -> usize {
         let mut count = 0;
-        for y in 0..self.height() - SEA_MONSTER_HEIGHT {
-            'monster_check: for x in 0..self.width() - SEA_MONSTER_WIDTH {
+        for y in 0..=last_y {
+            'monster_check: for x in 0..=last_x {
                 for (sy, row) in SEA_MONSTER.iter().enumerate().take(SEA_MONSTER_HEIGHT) {
                     let monster_row = row
                         .iter()
                         .enumerate()
                         .filter(|(_, tile_cell)| **tile_cell == X);
-                    let tile_row = self.0[y + sy][x..]
+                    let tile_row = self.0.row(y + sy)[x..]
                         .iter()
                         .take(SEA_MONSTER_WIDTH)
                         .enumerate()
@@ -135,11 +138,14 @@ impl Image {
     }
 }
 
-impl From<Tiles> for Image {
-    fn from(tiles: Tiles) -> Self {
-        let image_tiles = Image::line_up_tiles(tiles);
+impl TryFrom<Tiles> for Image {
+    type Error = Error;
+
+    fn try_from(tiles: Tiles) -> Result<Self> {
+        let image_tiles = Image::line_up_tiles(tiles)?;
 
-        let mut image: Vec<Vec<TileCell>> = Default::default();
+        let width = image_tiles.first().map_or(0, Vec::len) * (TILE_SIZE - 2);
+        let mut image = Vec::with_capacity(width * width);
         for row in image_tiles.iter().rev() {
             let mut datas = row
                 .iter()
@@ -147,27 +153,39 @@ impl From<Tiles> for Image {
                 .collect::<Vec<_>>();
 
             for _ in 0..TILE_SIZE - 2 {
-                let mut image_row = vec![];
                 for data in datas.iter_mut() {
-                    image_row.extend(data.next().unwrap().iter());
+                    image.extend(data.next().unwrap().iter());
                 }
-                image.push(image_row);
             }
         }
 
-        Self(image)
+        Ok(Self(Grid::from_vec(width, image)))
     }
 }
 
 impl std::fmt::Display for Image {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.0 {
-            for tile_cell in row {
-                write!(f, "{}", tile_cell)?
-            }
-            writeln!(f)?
-        }
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sea_monster() -> Image {
+        let cells = SEA_MONSTER.iter().flatten().copied().collect();
+        Image(Grid::from_vec(SEA_MONSTER_WIDTH, cells))
+    }
+
+    #[test]
+    fn sea_monsters_fill_the_image() {
+        assert_eq!(sea_monster().sea_monsters(), 1);
+    }
 
-        Ok(())
+    #[test]
+    fn sea_monsters_do_not_fit() {
+        assert_eq!(Image(Grid::filled(3, 3, X)).sea_monsters(), 0);
+        assert_eq!(Image::default().sea_monsters(), 0);
     }
 }
@@ -21,7 +21,7 @@ impl<T: Orientable + Clone> Iterator for Orientations<T> {
             return None;
         }
 
-        if self.orient_count % 2 == 0 {
+        if self.orient_count.is_multiple_of(2) {
             self.subject.flip();
         } else {
             self.subject.flip();
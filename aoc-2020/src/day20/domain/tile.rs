@@ -0,0 +1,284 @@
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+};
+
+use aoc_lib::{Error, Grid, Result, Scanner};
+
+use super::{direction::CardinalDir, orient::Orientable};
+
+pub const TILE_SIZE: usize = 10;
+pub const X: TileCell = TileCell(true);
+pub const O: TileCell = TileCell(false);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// A single cell represented in a Tile.
+///
+/// true = `#`, false = `.`
+pub struct TileCell(pub bool);
+
+impl std::fmt::Display for TileCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 {
+            Ok(write!(f, "#"))?
+        } else {
+            Ok(write!(f, "."))?
+        }
+    }
+}
+
+/// Tile - A small image from camera array
+#[derive(Debug, Clone)]
+pub struct Tile {
+    /// Unieuqe identifier of the tile
+    pub id: usize,
+    /// The image chunk data, [TILE_SIZE] cells wide and high
+    pub data: Grid<TileCell>,
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            data: Grid::filled(TILE_SIZE, TILE_SIZE, O),
+        }
+    }
+}
+
+impl PartialEq for Tile {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+impl Eq for Tile {}
+
+impl Hash for Tile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Orientable for Tile {
+    fn flip(&mut self) {
+        self.data.flip_vertical()
+    }
+
+    fn rotate(&mut self) {
+        self.data.rotate_right()
+    }
+}
+
+impl Tile {
+    pub fn top_border(&self) -> impl Iterator<Item = &TileCell> {
+        self.data.row(0).iter()
+    }
+
+    pub fn bottom_border(&self) -> impl Iterator<Item = &TileCell> {
+        self.data.row(TILE_SIZE - 1).iter()
+    }
+
+    pub fn right_border(&self) -> impl Iterator<Item = &TileCell> {
+        self.data.column(TILE_SIZE - 1)
+    }
+
+    pub fn left_border(&self) -> impl Iterator<Item = &TileCell> {
+        self.data.column(0)
+    }
+
+    pub fn stitch_to(&self, other: &Self) -> Option<CardinalDir> {
+        use CardinalDir::*;
+
+        if self.top_border().eq(other.bottom_border()) {
+            Some(North)
+        } else if self.right_border().eq(other.left_border()) {
+            Some(East)
+        } else if self.bottom_border().eq(other.top_border()) {
+            Some(South)
+        } else if self.left_border().eq(other.right_border()) {
+            Some(West)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a key for every border which stays the same however the tile is
+    /// flipped or rotated, the smaller of the border read either way as bits.
+    pub fn border_keys(&self) -> [u16; 4] {
+        fn key<'a>(border: impl Iterator<Item = &'a TileCell>) -> u16 {
+            let bits = border.fold(0, |bits, cell| bits << 1 | u16::from(cell.0));
+            bits.min(bits.reverse_bits() >> (16 - TILE_SIZE))
+        }
+
+        [
+            key(self.top_border()),
+            key(self.right_border()),
+            key(self.bottom_border()),
+            key(self.left_border()),
+        ]
+    }
+
+    /// Whether the tiles can be stitched together in some orientation.
+    pub fn shares_border(&self, other: &Self) -> bool {
+        let keys = other.border_keys();
+        self.border_keys().iter().any(|key| keys.contains(key))
+    }
+
+    pub fn exclude_borders(&self) -> impl Iterator<Item = &[TileCell]> {
+        self.data.rect(1..TILE_SIZE - 1, 1..TILE_SIZE - 1)
+    }
+}
+
+impl TryFrom<Scanner<'_>> for Tile {
+    type Error = Error;
+
+    fn try_from(mut block: Scanner<'_>) -> Result<Self> {
+        let id: usize = aoc_lib::scan!(block.line(), "Tile {}:")?;
+
+        let data = block
+            .grid(|cell| match cell {
+                '#' => Some(X),
+                '.' => Some(O),
+                _ => None,
+            })
+            .map_err(|err| Error::parse(format!("tile {}: {}", id, err.message)))?;
+
+        if (data.width(), data.height()) != (TILE_SIZE, TILE_SIZE) {
+            return Err(Error::parse(format!(
+                "tile {} is not {}x{} cells",
+                id, TILE_SIZE, TILE_SIZE
+            )));
+        }
+
+        Ok(Self { id, data })
+    }
+}
+
+impl TryFrom<&str> for Tile {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        Tile::try_from(Scanner::new(input))
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tile {}:\n{}", self.id, self.data)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tiles(pub HashSet<Tile>);
+
+impl TryFrom<&str> for Tiles {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        Scanner::new(input)
+            .blocks()
+            .map(Tile::try_from)
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_TILE: &str = "\
+        Tile 2311:\n\
+        ..##.#..#.\n\
+        ##..#.....\n\
+        #...##..#.\n\
+        ####.#...#\n\
+        ##.##.###.\n\
+        ##...#.###\n\
+        .#.#.#..##\n\
+        ..#....#..\n\
+        ###...#.#.\n\
+        ..###..###\n";
+
+    #[test]
+    fn tile_from_str_works() {
+        let tile = Tile::try_from(EXAMPLE_TILE).unwrap();
+        assert_eq!(tile.id, 2311);
+
+        assert_eq!(tile.data.row(0), [O, O, X, X, O, X, O, O, X, O]);
+        assert_eq!(tile.data.row(9), [O, O, X, X, X, O, O, X, X, X]);
+        assert_eq!(tile.to_string(), EXAMPLE_TILE);
+    }
+
+    #[test]
+    fn tile_try_from_invalid() {
+        assert!(Tile::try_from("Tile x:\n").is_err());
+        assert!(Tile::try_from(&EXAMPLE_TILE[..50]).is_err());
+        assert!(Tile::try_from(EXAMPLE_TILE.replace('#', "?").as_str()).is_err());
+
+        let input = format!(
+            "{}\n{}",
+            EXAMPLE_TILE,
+            EXAMPLE_TILE.replace("..##.#..#.", "..##.#..?.")
+        );
+        assert_eq!(
+            Tiles::try_from(input.as_str()).unwrap_err().message,
+            "tile 2311: line 14, column 9: unexpected `?`"
+        );
+    }
+
+    #[test]
+    fn border_keys_ignore_orientation() {
+        let tile = Tile::try_from(EXAMPLE_TILE).unwrap();
+        let mut keys = tile.border_keys();
+        keys.sort_unstable();
+
+        for orientation in tile.clone().orientations() {
+            let mut oriented = orientation.border_keys();
+            oriented.sort_unstable();
+            assert_eq!(oriented, keys);
+            assert!(orientation.shares_border(&tile));
+        }
+        assert!(!tile.shares_border(&Tile::default()));
+    }
+
+    #[test]
+    fn tile_flip_works() {
+        let mut tile = Tile::default();
+        tile.data[(0, 0)] = X;
+        tile.flip();
+
+        assert_eq!(tile.data[(0, 9)], X);
+    }
+
+    #[test]
+    fn tile_rotate_works() {
+        let mut tile = Tile::default();
+        tile.data[(0, 0)] = X;
+        tile.rotate();
+
+        assert_eq!(tile.data[(9, 0)], X);
+        assert_eq!(tile.data[(0, 0)], O);
+
+        tile.rotate();
+        tile.rotate();
+        tile.rotate();
+        assert_eq!(tile.data[(9, 0)], O);
+        assert_eq!(tile.data[(0, 0)], X);
+    }
+
+    #[test]
+    fn tile_exclude_borders_works() {
+        let tile = Tile::try_from(EXAMPLE_TILE).unwrap();
+        assert_eq!(tile.data.row(0), [O, O, X, X, O, X, O, O, X, O]);
+        assert_eq!(
+            tile.data.row(TILE_SIZE - 1),
+            [O, O, X, X, X, O, O, X, X, X,]
+        );
+
+        let data: Vec<_> = tile.exclude_borders().collect();
+        assert_eq!(data[0].len(), TILE_SIZE - 2);
+        assert_eq!(data[0], [X, O, O, X, O, O, O, O]);
+        assert_eq!(data[TILE_SIZE - 3], [X, X, O, O, O, X, O, X]);
+    }
+}
@@ -0,0 +1,80 @@
+//! [Day 20: Jurassic Jigsaw](https://adventofcode.com/2020/day/20)
+
+pub mod domain;
+
+use std::convert::TryFrom;
+
+use aoc_lib::{Error, Result, Solution};
+use domain::{
+    image::{Image, SEA_MONSTER_X_COUNT},
+    orient::Orientable,
+    tile::{Tiles, X},
+};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/20.txt"));
+
+#[derive(Default)]
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Tiles;
+    type A = usize;
+    type B = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Tiles::try_from(input)
+    }
+
+    fn part1(&self, tiles: &Self::Parsed) -> Result<Self::A> {
+        Ok(tiles
+            .0
+            .iter()
+            .filter(|tile| {
+                let nbors = tiles
+                    .0
+                    .iter()
+                    .filter(|other| tile != other && tile.shares_border(other))
+                    .count();
+
+                nbors == 2
+            })
+            .map(|tile| tile.id)
+            .product())
+    }
+
+    fn part2(&self, tiles: &Self::Parsed) -> Result<Self::B> {
+        let image = Image::try_from(tiles.clone())?;
+
+        let (sea_monsters, image) = image
+            .orientations()
+            .map(|image| (image.sea_monsters(), image))
+            .find(|(sea_monsters, _)| *sea_monsters != 0)
+            .ok_or_else(|| {
+                Error::no_answer("could not find any sea monster in any orientation :(")
+            })?;
+
+        Ok(
+            image.0.iter().filter(|cell| **cell == X).count()
+                - (sea_monsters * SEA_MONSTER_X_COUNT),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day20, INPUT};
+    use aoc_lib::Solution;
+
+    aoc_lib::test_table! {
+        Day20;
+        part1_example: Part1 { aoc_lib::example!("20.txt") => 20899048083289u64 },
+        part2_example: Part2 { aoc_lib::example!("20.txt") => 273 },
+        part1_answer: Part1 { INPUT => 19955159604613u64 },
+        part2_answer: Part2 { INPUT => 1639 },
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Day20.parse("Tile 1:\n#.#\n").is_err());
+    }
+}
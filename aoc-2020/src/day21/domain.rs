@@ -1,43 +1,54 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
+
+use aoc_lib::{Error, Result, Scanner};
 
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
-pub(super) struct Ingredient<'a>(pub &'a str);
-pub(super) type Ingredients<'a> = HashSet<Ingredient<'a>>;
+pub struct Ingredient<'a>(pub &'a str);
+pub type Ingredients<'a> = HashSet<Ingredient<'a>>;
 
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
-pub(super) struct Allergen<'a>(pub &'a str);
-pub(super) type Allergens<'a> = HashSet<Allergen<'a>>;
+pub struct Allergen<'a>(pub &'a str);
+pub type Allergens<'a> = HashSet<Allergen<'a>>;
 
 #[derive(Default)]
-pub(super) struct Food<'a> {
-    pub(super) ingredients: Ingredients<'a>,
-    pub(super) allergens: Allergens<'a>,
+pub struct Food<'a> {
+    pub ingredients: Ingredients<'a>,
+    pub allergens: Allergens<'a>,
 }
-pub(super) struct Foods<'a>(pub(super) Vec<Food<'a>>);
-
-impl<'a> From<&'a str> for Foods<'a> {
-    fn from(input: &'a str) -> Self {
-        let mut foods: Vec<Food> = Default::default();
-
-        for line in input.trim().lines() {
-            let mut split = line.split(" (contains ");
-            let ingredients: Ingredients =
-                split.next().unwrap().split(' ').map(Ingredient).collect();
-            let allergens: Allergens = split
-                .next()
-                .unwrap()
-                .trim_end_matches(')')
-                .split(", ")
-                .map(Allergen)
-                .collect();
-
-            foods.push(Food {
-                ingredients,
-                allergens,
+pub struct Foods<'a>(pub Vec<Food<'a>>);
+
+impl<'a> TryFrom<&'a str> for Foods<'a> {
+    type Error = Error;
+
+    fn try_from(input: &'a str) -> Result<Self> {
+        Scanner::new(input.trim())
+            .lines()
+            .map(|line| {
+                let (mut ingredients, mut allergens): (Scanner, Scanner) =
+                    aoc_lib::scan!(line, "{} (contains {})")?;
+                let food = Food {
+                    ingredients: ingredients
+                        .separated(" ", Scanner::identifier)?
+                        .into_iter()
+                        .map(Ingredient)
+                        .collect(),
+                    allergens: allergens
+                        .separated(", ", Scanner::identifier)?
+                        .into_iter()
+                        .map(Allergen)
+                        .collect(),
+                };
+                ingredients.end()?;
+                allergens.end()?;
+
+                Ok(food)
             })
-        }
-
-        Self(foods)
+            .collect::<Result<_>>()
+            .map(Self)
     }
 }
 
@@ -45,14 +56,14 @@ impl Foods<'_> {
     /// Calculates how many times an ingredient is included in a food with
     /// the specified allergen. Values in the HashMap are sorted in DESC
     /// order by their occurrences.
-    fn aler_ingrs_occurrences(&self) -> HashMap<Allergen, Vec<(Ingredient, usize)>> {
+    fn aler_ingrs_occurrences(&self) -> HashMap<Allergen<'_>, Vec<(Ingredient<'_>, usize)>> {
         let mut occurs: HashMap<Allergen, HashMap<Ingredient, usize>> = Default::default();
         for food in &self.0 {
             for al in &food.allergens {
                 for igr in &food.ingredients {
                     occurs
                         .entry(*al)
-                        .or_insert_with(Default::default)
+                        .or_default()
                         .entry(*igr)
                         .and_modify(|ocur| *ocur += 1)
                         .or_insert(1);
@@ -63,7 +74,7 @@ impl Foods<'_> {
         let mut sorted_occurs: HashMap<Allergen, Vec<(Ingredient, usize)>> = Default::default();
         for (al, ingrs) in occurs {
             let mut ingrs: Vec<_> = ingrs.into_iter().collect();
-            ingrs.sort_by(|a, b| b.1.cmp(&a.1));
+            ingrs.sort_by_key(|(_, ocur)| Reverse(*ocur));
 
             let (_, highest_occurs) = ingrs[0];
             sorted_occurs.insert(
@@ -78,7 +89,7 @@ impl Foods<'_> {
         sorted_occurs
     }
 
-    pub(super) fn allergenic_ingredients(&self) -> HashMap<Allergen, Ingredient> {
+    pub fn allergenic_ingredients(&self) -> HashMap<Allergen<'_>, Ingredient<'_>> {
         let mut occurs = self.aler_ingrs_occurrences();
         let mut result: HashMap<Allergen, Ingredient> = Default::default();
         while !occurs.is_empty() {
@@ -109,7 +120,7 @@ impl Foods<'_> {
         result
     }
 
-    fn all_ingredients(&self) -> HashSet<Ingredient> {
+    fn all_ingredients(&self) -> HashSet<Ingredient<'_>> {
         self.0
             .iter()
             .fold(Default::default(), |mut ingredients, food| {
@@ -118,7 +129,7 @@ impl Foods<'_> {
             })
     }
 
-    pub(super) fn non_allergenic_ingredients(&self) -> HashSet<Ingredient> {
+    pub fn non_allergenic_ingredients(&self) -> HashSet<Ingredient<'_>> {
         let al_ingrs = self
             .allergenic_ingredients()
             .into_iter()
@@ -0,0 +1,68 @@
+//! [Day 21: Allergen Assessment](https://adventofcode.com/2020/day/21)
+
+pub mod domain;
+
+use std::convert::TryFrom;
+
+use aoc_lib::{Result, TryPart1, TryPart2};
+use domain::Foods;
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/21.txt"));
+
+#[derive(Default)]
+pub struct Day21;
+
+impl TryPart1 for Day21 {
+    type A = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        let foods = Foods::try_from(input)?;
+        let non_al_ingrs = foods.non_allergenic_ingredients();
+
+        Ok(foods.0.iter().fold(0, |count, food| {
+            count + non_al_ingrs.intersection(&food.ingredients).count()
+        }))
+    }
+}
+
+impl TryPart2 for Day21 {
+    type B = String;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        let foods = Foods::try_from(input)?;
+        let mut al_ingrs: Vec<_> = foods.allergenic_ingredients().into_iter().collect();
+        al_ingrs.sort_by_key(|(a, _)| *a);
+
+        Ok(al_ingrs
+            .into_iter()
+            .map(|(_, ingr)| ingr.0)
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day21, INPUT};
+    use aoc_lib::TryPart1;
+
+    aoc_lib::test_table! {
+        Day21;
+        part1_example: Part1 { aoc_lib::example!("21.txt") => 5 },
+        part2_example: Part2 { aoc_lib::example!("21.txt") => "mxmxvkd,sqjhc,fvjkl" },
+        part1_answer: Part1 { INPUT => 2211 },
+        part2_answer: Part2 { INPUT => "vv,nlxsmb,rnbhjk,bvnkk,ttxvphb,qmkz,trmzkcfg,jpvz" },
+    }
+
+    #[test]
+    fn part1_invalid() {
+        let err = TryPart1::try_solve(
+            &Day21,
+            "sqjhc fvjkl (contains soy)\nsqjhc (contains fish, )",
+        );
+        assert_eq!(
+            err.unwrap_err().message,
+            "line 2, column 23: expected an identifier, found `)`"
+        );
+    }
+}
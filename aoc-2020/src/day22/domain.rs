@@ -1,19 +1,19 @@
 use std::collections::{HashSet, VecDeque};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-pub(super) struct Card(pub usize);
+pub struct Card(pub usize);
 
 #[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
-pub(super) struct Deck(pub VecDeque<Card>);
+pub struct Deck(pub VecDeque<Card>);
 
 impl Deck {
-    pub(super) fn score(&self) -> usize {
+    pub fn score(&self) -> usize {
         self.0.iter().rev().zip(1..).map(|(c, m)| m * c.0).sum()
     }
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
-pub(super) struct Game(Deck, Deck);
+pub struct Game(Deck, Deck);
 
 impl From<&str> for Game {
     fn from(input: &str) -> Self {
@@ -35,7 +35,7 @@ impl From<&str> for Game {
 }
 
 impl Game {
-    pub(super) fn simulate_combat(&self) -> Deck {
+    pub fn simulate_combat(&self) -> Deck {
         let mut game_copy = (*self).clone();
         while !game_copy.0 .0.is_empty() && !game_copy.1 .0.is_empty() {
             let c1 = game_copy.0 .0.pop_front().unwrap();
@@ -96,7 +96,7 @@ impl Game {
         !game.0 .0.is_empty()
     }
 
-    pub(super) fn simulate_recursive_combat(&self) -> Deck {
+    pub fn simulate_recursive_combat(&self) -> Deck {
         let mut game_copy = (*self).clone();
         if Self::recursive_combat(&mut game_copy) {
             game_copy.0
@@ -0,0 +1,40 @@
+//! [Day 22: Crab Combat](https://adventofcode.com/2020/day/22)
+
+pub mod domain;
+
+use aoc_lib::{Part1, Part2};
+use domain::Game;
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/22.txt"));
+
+#[derive(Default)]
+pub struct Day22;
+
+impl Part1 for Day22 {
+    type A = usize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        Game::from(input).simulate_combat().score()
+    }
+}
+
+impl Part2 for Day22 {
+    type B = usize;
+
+    fn solve(&self, input: &str) -> Self::B {
+        Game::from(input).simulate_recursive_combat().score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day22, INPUT};
+
+    aoc_lib::test_table! {
+        Day22;
+        part1_example: Part1 { aoc_lib::example!("22.txt") => 306 },
+        part2_example: Part2 { aoc_lib::example!("22.txt") => 291 },
+        part1_answer: Part1 { INPUT => 33010 },
+        part2_answer: Part2 { INPUT => 32769 },
+    }
+}
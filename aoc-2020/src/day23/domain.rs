@@ -1,8 +1,8 @@
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub(super) struct Cup(pub usize);
+pub struct Cup(pub usize);
 
 #[derive(Debug)]
-pub(super) struct Cups(pub Vec<Cup>);
+pub struct Cups(pub Vec<Cup>);
 
 impl From<&str> for Cups {
     fn from(input: &str) -> Self {
@@ -37,17 +37,17 @@ impl From<&str> for Cups {
 /// Cups: [3 4 2 1]
 /// ```
 #[derive(Debug)]
-pub(super) struct CrabCups {
+pub struct CrabCups {
     cups: Vec<usize>,
     length: usize,
 }
 
 impl CrabCups {
-    pub(super) fn new(cups: &Cups) -> Self {
+    pub fn new(cups: &Cups) -> Self {
         Self::with_length(cups, 0)
     }
 
-    pub(super) fn with_length(cups: &Cups, length: usize) -> Self {
+    pub fn with_length(cups: &Cups, length: usize) -> Self {
         let cups_length = cups.0.len();
         let length = cups_length.max(length);
         let mut inner: Vec<usize> = vec![0; length * 2 + 1];
@@ -78,7 +78,7 @@ impl CrabCups {
         }
     }
 
-    pub(super) fn next(&self, cup: usize) -> usize {
+    pub fn next(&self, cup: usize) -> usize {
         self.cups[self.cups[cup * 2] - 1]
     }
 
@@ -104,7 +104,7 @@ impl CrabCups {
         }
     }
 
-    pub(super) fn do_move(&mut self) {
+    pub fn do_move(&mut self) {
         let curr_cup = self.curr();
         let dest_cup = self.dest_cup();
 
@@ -121,7 +121,7 @@ impl CrabCups {
         self.cups[0] = self.cups[curr_cup * 2];
     }
 
-    pub(super) fn label(&self) -> String {
+    pub fn label(&self) -> String {
         let mut result = String::new();
 
         let mut cup = self.next(1);
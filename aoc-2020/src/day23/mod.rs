@@ -0,0 +1,85 @@
+//! [Day 23: Crab Cups](https://adventofcode.com/2020/day/23)
+
+pub mod domain;
+
+use aoc_lib::{Part1, Result, TryPart2};
+pub use domain::{CrabCups, Cups};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/23.txt"));
+
+#[derive(Clone)]
+pub struct Day23 {
+    pub part1_moves: usize,
+    pub part2_moves: usize,
+}
+
+impl Default for Day23 {
+    fn default() -> Self {
+        Self {
+            part1_moves: 100,
+            part2_moves: 10_000_000,
+        }
+    }
+}
+
+aoc_lib::params! {
+    Day23 {
+        /// Moves of the crab in part 1.
+        part1_moves: usize,
+        /// Moves of the crab in part 2.
+        part2_moves: usize,
+    }
+}
+
+impl Part1 for Day23 {
+    type A = String;
+
+    fn solve(&self, input: &str) -> Self::A {
+        let cups = Cups::from(input);
+        let mut crab_cups = CrabCups::new(&cups);
+
+        for _ in 0..self.part1_moves {
+            crab_cups.do_move();
+        }
+
+        crab_cups.label()
+    }
+}
+
+impl TryPart2 for Day23 {
+    type B = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        let cups = Cups::from(input);
+        let mut crab_cups = CrabCups::with_length(&cups, 1_000_000);
+
+        for i in 0..self.part2_moves {
+            // Polling is cheap compared to 100k moves
+            if i % 100_000 == 0 {
+                aoc_lib::check_cancelled()?;
+            }
+            crab_cups.do_move();
+        }
+
+        let a = crab_cups.next(1);
+        let b = crab_cups.next(a);
+        Ok(a * b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day23, INPUT};
+
+    aoc_lib::test_table! {
+        Day23 { part1_moves: 10, ..Day23::default() };
+        part1_example: Part1 { "389125467" => "92658374" },
+    }
+
+    aoc_lib::test_table! {
+        Day23::default();
+        part2_example: Part2 { "389125467" => 149245887792u64 },
+        part1_answer: Part1 { INPUT => "24798635" },
+        part2_answer: Part2 { INPUT => 12757828710u64 },
+    }
+}
@@ -0,0 +1,87 @@
+use std::{collections::HashSet, convert::TryFrom};
+
+use aoc_lib::{
+    hex::{Cube, Pointy},
+    Automaton, Backend, Error, LifeLike, Result, Scanner,
+};
+
+/// A tile is identified by a series of directions.
+#[derive(Debug, Default)]
+pub struct Tile(Vec<Pointy>);
+
+/// Because the tiles are hexagonal, every tile has six neighbors: east,
+/// southeast, southwest, west, northwest, and northeast. These directions are
+/// given in your list, respectively, as `e`, `se`, `sw`, `w`, `nw`, and `ne`.
+impl TryFrom<Scanner<'_>> for Tile {
+    type Error = Error;
+
+    fn try_from(mut line: Scanner<'_>) -> Result<Self> {
+        let mut tile: Tile = Default::default();
+
+        while !line.is_empty() {
+            tile.0.push(line.one_of(vec![
+                ("e", Pointy::E),
+                ("se", Pointy::SE),
+                ("sw", Pointy::SW),
+                ("w", Pointy::W),
+                ("nw", Pointy::NW),
+                ("ne", Pointy::NE),
+            ])?);
+        }
+
+        Ok(tile)
+    }
+}
+
+/// Each element in the list identifies a single tile that needs to be flipped
+/// by giving a series of steps starting from a reference tile in the very
+/// center of the room (0, 0, 0). (Every line starts from the same reference
+/// tile.)
+#[derive(Debug)]
+pub struct Tiles(Vec<Tile>);
+
+impl TryFrom<&str> for Tiles {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        Scanner::new(input)
+            .lines()
+            .map(Tile::try_from)
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct BlackTiles(pub HashSet<Cube>);
+
+impl BlackTiles {
+    pub fn flip_tiles(lines: &Tiles) -> Self {
+        let mut result: Self = Default::default();
+
+        for steps in &lines.0 {
+            let tile: Cube = steps.0.iter().map(|dir| dir.offset()).sum();
+
+            if !result.0.insert(tile) {
+                result.0.remove(&tile);
+            }
+        }
+
+        result
+    }
+
+    /// Returns the living art exhibit, which flips the tiles every day.
+    pub fn exhibit(self) -> Automaton<impl Backend> {
+        /*
+         * Tiles are/stay flipped according to the following rules:
+         *
+         * - Any black tile with zero or more than 2 black tiles immediately
+         *   adjacent to it is flipped to white.
+         * - Any white tile with exactly 2 black tiles immediately adjacent to
+         *   it is flipped to black.
+         */
+        let rule = LifeLike::new(&[2], &[1, 2]);
+
+        Automaton::sparse(self.0, Cube::neighbours, rule)
+    }
+}
@@ -0,0 +1,74 @@
+//! [Day 24: Lobby Layout](https://adventofcode.com/2020/day/24)
+
+pub mod domain;
+
+use std::convert::TryFrom;
+
+use aoc_lib::{Result, TryPart1, TryPart2};
+use domain::{BlackTiles, Tiles};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/24.txt"));
+
+#[derive(Clone)]
+pub struct Day24 {
+    pub days: usize,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Self { days: 100 }
+    }
+}
+
+aoc_lib::params! {
+    Day24 {
+        /// Days the tiles are flipped in part 2.
+        days: usize,
+    }
+}
+
+impl TryPart1 for Day24 {
+    type A = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        Ok(BlackTiles::flip_tiles(&Tiles::try_from(input)?).0.len())
+    }
+}
+
+impl TryPart2 for Day24 {
+    type B = usize;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        let mut exhibit = BlackTiles::flip_tiles(&Tiles::try_from(input)?).exhibit();
+        exhibit.run(self.days);
+        Ok(exhibit.population())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day24, INPUT};
+    use aoc_lib::TryPart1;
+
+    aoc_lib::test_table! {
+        Day24::default();
+        part1_example: Part1 { aoc_lib::example!("24.txt") => 10 },
+        part2_example: Part2 { aoc_lib::example!("24.txt") => 2208 },
+        part1_answer: Part1 { INPUT => 469 },
+        part2_answer: Part2 { INPUT => 4353 },
+    }
+
+    aoc_lib::test_table! {
+        Day24 { days: 10 };
+        part2_example_10_days: Part2 { aoc_lib::example!("24.txt") => 37 },
+    }
+
+    #[test]
+    fn part1_invalid() {
+        let err = TryPart1::try_solve(&Day24::default(), "esew\nnwwsx");
+        assert_eq!(
+            err.unwrap_err().message,
+            "line 2, column 4: expected one of `e`, `se`, `sw`, `w`, `nw`, `ne`, found `sx`"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 const MAGIC: usize = 20201227;
 const SUBJ_NUM: usize = 7;
 
-pub(crate) fn loop_size(public_key: usize) -> usize {
+pub fn loop_size(public_key: usize) -> usize {
     let mut val = 1;
     let mut lsize = 0;
     while val != public_key {
@@ -11,7 +11,7 @@ pub(crate) fn loop_size(public_key: usize) -> usize {
     lsize
 }
 
-pub(crate) fn encryption_key(public_key: usize, lsize: usize) -> usize {
+pub fn encryption_key(public_key: usize, lsize: usize) -> usize {
     let mut key = 1;
     for _ in 0..lsize {
         key = (key * public_key) % MAGIC;
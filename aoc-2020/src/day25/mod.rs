@@ -0,0 +1,39 @@
+//! [Day 25: Combo Breaker](https://adventofcode.com/2020/day/25)
+
+pub mod domain;
+
+use aoc_lib::{Part1, Part2};
+use domain::{encryption_key, loop_size};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/25.txt"));
+
+#[derive(Default)]
+pub struct Day25;
+
+impl Part1 for Day25 {
+    type A = usize;
+
+    fn solve(&self, input: &str) -> Self::A {
+        let mut keys = input.lines().flat_map(str::parse);
+        encryption_key(keys.next().unwrap(), loop_size(keys.next().unwrap()))
+    }
+}
+
+impl Part2 for Day25 {
+    type B = &'static str;
+
+    fn solve(&self, _: &str) -> Self::B {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day25, INPUT};
+
+    aoc_lib::test_table! {
+        Day25;
+        part1_example: Part1 { "5764801\n17807724" => 14897079 },
+        part1_answer: Part1 { INPUT => 4441893 },
+    }
+}
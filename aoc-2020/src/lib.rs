@@ -0,0 +1,35 @@
+//! Solutions for the [Advent of Code 2020](https://adventofcode.com/2020) puzzles.
+
+pub mod day09;
+pub mod day17;
+pub mod day18;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use aoc_lib::Registry;
+
+pub const YEAR: u16 = 2020;
+
+/// Registers every solution of this year.
+pub fn register(registry: &mut Registry) {
+    registry.set_dir(YEAR, env!("CARGO_MANIFEST_DIR"));
+    registry.register_with_params(YEAR, 9, day09::INPUT, day09::Day09::default());
+    registry.register_with_params(YEAR, 17, day17::INPUT, day17::Day17::default());
+    registry.register(YEAR, 18, day18::INPUT, day18::Day18);
+    registry.register(YEAR, 20, day20::INPUT, day20::Day20);
+    registry.register(YEAR, 21, day21::INPUT, day21::Day21);
+    registry.register(YEAR, 22, day22::INPUT, day22::Day22);
+    registry.register_with_params(YEAR, 23, day23::INPUT, day23::Day23::default());
+    registry.register_with_params(YEAR, 24, day24::INPUT, day24::Day24::default());
+    registry.register(YEAR, 25, day25::INPUT, day25::Day25);
+}
+
+/// Runs and prints the answers of a single day of this year, see
+/// [aoc_lib::solve_print](aoc_lib::solve_print).
+pub fn solve_print(day: u8) {
+    aoc_lib::solve_print(register, YEAR, day);
+}
@@ -0,0 +1,207 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A global allocator which counts the allocations of every thread, so
+/// [measure](measure) can report how much a solution allocates.
+///
+/// Counting is opt-in, a binary installs the allocator with:
+///
+/// ```
+/// #[global_allocator]
+/// static ALLOC: aoc_lib::CountingAlloc = aoc_lib::CountingAlloc;
+/// ```
+pub struct CountingAlloc;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            allocs: Cell::new(0),
+            bytes: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+/// The counters of a single thread. Live bytes may become negative when a
+/// thread frees memory allocated by another thread.
+struct Counters {
+    allocs: Cell<u64>,
+    bytes: Cell<u64>,
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+impl Counters {
+    fn alloc(&self, size: usize) {
+        self.allocs.set(self.allocs.get() + 1);
+        self.bytes.set(self.bytes.get() + size as u64);
+        self.resize(size as i64);
+    }
+
+    fn resize(&self, delta: i64) {
+        let live = self.live.get() + delta;
+        self.live.set(live);
+        self.peak.set(self.peak.get().max(live));
+    }
+}
+
+fn record(f: impl FnOnce(&Counters)) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    // The counters are gone while the thread is being torn down
+    let _ = COUNTERS.try_with(f);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(|c| c.alloc(layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(|c| c.alloc(layout.size()));
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(|c| c.resize(-(layout.size() as i64)));
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(|c| {
+            c.allocs.set(c.allocs.get() + 1);
+            c.bytes.set(c.bytes.get() + new_size as u64);
+            c.resize(new_size as i64 - layout.size() as i64);
+        });
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// What was allocated on a thread while running a measured closure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Amount of allocations, including reallocations.
+    pub allocs: u64,
+    /// Total bytes requested by all allocations.
+    pub bytes: u64,
+    /// Highest amount of bytes live at once, on top of what was live before.
+    pub peak: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocs,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// Displays an amount of bytes with a binary unit.
+struct Bytes(u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit + 1 < UNITS.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Runs `f` and returns what it allocated on the current thread, or `None` if
+/// the [CountingAlloc](CountingAlloc) is not installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let start = COUNTERS.with(|c| {
+        let start = (c.allocs.get(), c.bytes.get(), c.live.get(), c.peak.get());
+        c.peak.set(c.live.get());
+        start
+    });
+
+    let result = f();
+
+    if !INSTALLED.load(Ordering::Relaxed) {
+        return (result, None);
+    }
+
+    let stats = COUNTERS.with(|c| {
+        let (allocs, bytes, live, peak) = start;
+        let stats = AllocStats {
+            allocs: c.allocs.get() - allocs,
+            bytes: c.bytes.get() - bytes,
+            peak: (c.peak.get() - live).max(0) as u64,
+        };
+        // An outer measurement still sees the peak of this one
+        c.peak.set(c.peak.get().max(peak));
+        stats
+    });
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests of aoc-lib run with the allocator installed
+    #[global_allocator]
+    static ALLOC: CountingAlloc = CountingAlloc;
+
+    #[test]
+    fn measure_counts_allocations() {
+        let (_, stats) = measure(|| {
+            let small = vec![0u8; 100];
+            drop(small);
+            let mut v: Vec<u8> = Vec::with_capacity(1000);
+            v.extend(0..=255);
+            v
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(stats.allocs, 2);
+        assert_eq!(stats.bytes, 1100);
+        assert_eq!(stats.peak, 1000);
+
+        let (_, stats) = measure(|| 1 + 1);
+        assert_eq!(stats, Some(AllocStats::default()));
+    }
+
+    #[test]
+    fn measure_nested_keeps_outer_peak() {
+        let (_, outer) = measure(|| {
+            let big = vec![0u8; 4096];
+            drop(big);
+            let (_, inner) = measure(|| vec![0u8; 10]);
+            assert_eq!(inner.unwrap().peak, 10);
+        });
+
+        assert_eq!(outer.unwrap().peak, 4096);
+    }
+
+    #[test]
+    fn bytes_display_works() {
+        assert_eq!(Bytes(10).to_string(), "10 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+use crate::{Error, Part, Result, SolveReport, Status};
+
+/// The known answers of a year, usually stored as `answers.toml` next to the
+/// `inputs/` directory of the year.
+///
+/// Only a small subset of TOML is supported, a table per day with a string or
+/// integer answer per part:
+///
+/// ```toml
+/// [01]
+/// part1 = 138
+/// part2 = "1771"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+impl Answers {
+    /// Reads the answers file at the given path. A missing file has no
+    /// answers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the answers to the given path with a header comment.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(
+            path,
+            format!(
+                "# Answers to the puzzle inputs in `inputs/`, checked by `aoc verify`.\n\n{}",
+                self
+            ),
+        )
+    }
+
+    /// Parses the contents of an answers file.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut answers = Self::default();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let invalid = |msg: &str| Error::parse(format!("line {}: {}", i + 1, msg));
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            } else if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                match table.trim().parse() {
+                    Ok(d @ 1..=25) => day = Some(d),
+                    _ => return Err(invalid("expected a day from 01 to 25")),
+                }
+            } else if let Some((key, value)) = line.split_once('=') {
+                let day = day.ok_or_else(|| invalid("answer outside of a day"))?;
+                let part = match key.trim() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(invalid("expected `part1` or `part2`")),
+                };
+                let value = parse_value(value.trim()).ok_or_else(|| invalid("invalid answer"))?;
+
+                if answers.0.insert((day, part), value).is_some() {
+                    return Err(invalid("duplicate answer"));
+                }
+            } else {
+                return Err(invalid("expected a `[day]` or `part = answer`"));
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, answer: impl Into<String>) {
+        self.0.insert((day, part), answer.into());
+    }
+
+    /// Compares the report with the known answer of its day and part.
+    pub fn verify(&self, report: &SolveReport) -> Verdict {
+        match self.get(report.day, report.part) {
+            None => Verdict::Missing,
+            Some(expected) if report.status == Status::Solved && report.answer == expected => {
+                Verdict::Pass
+            }
+            Some(_) => Verdict::Fail,
+        }
+    }
+}
+
+/// Writes the answers back in the format [Answers::parse](Answers::parse)
+/// expects.
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut prev = None;
+        for ((day, part), answer) in &self.0 {
+            if prev != Some(*day) {
+                if prev.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{:02}]", day)?;
+                prev = Some(*day);
+            }
+
+            let key = match part {
+                Part::One => "part1",
+                Part::Two => "part2",
+            };
+            if !answer.is_empty() && answer.bytes().all(|b| b.is_ascii_digit()) {
+                writeln!(f, "{} = {}", key, answer)?;
+            } else {
+                let escaped = answer.replace('\\', "\\\\").replace('"', "\\\"");
+                writeln!(f, "{} = \"{}\"", key, escaped)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The outcome of comparing an answer with the known answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// Removes a `#` comment unless it is inside a string.
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '\\' if in_string => {
+                escaped = !escaped;
+                continue;
+            }
+            '"' if !escaped => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+/// Parses a basic string with `\"` and `\\` escapes or an integer.
+pub(crate) fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let mut s = String::new();
+        let mut chars = quoted.chars();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    c @ '"' | c @ '\\' => s.push(c),
+                    _ => return None,
+                },
+                c => s.push(c),
+            }
+        }
+        chars.as_str().is_empty().then_some(s)
+    } else {
+        let digits = value.strip_prefix('-').unwrap_or(value).replace('_', "");
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            Some(value.replace('_', ""))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+        # Answers of the embedded inputs\n\
+        [01]\n\
+        part1 = 138\n\
+        part2 = \"1771\" # the basement\n\
+        \n\
+        [21]\n\
+        part2 = \"vv,nlxsmb # \\\"x\\\"\"\n";
+
+    #[test]
+    fn parse_works() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("138"));
+        assert_eq!(answers.get(1, Part::Two), Some("1771"));
+        assert_eq!(answers.get(21, Part::One), None);
+        assert_eq!(answers.get(21, Part::Two), Some("vv,nlxsmb # \"x\""));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Answers::parse("part1 = 1").is_err());
+        assert!(Answers::parse("[26]").is_err());
+        assert!(Answers::parse("[01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[01]\npart1 = 1\npart1 = 2").is_err());
+        assert!(Answers::parse("[01]\npart1 = \"1").is_err());
+        assert!(Answers::parse("[01]\npart1 = one").is_err());
+
+        let err = Answers::parse("[01]\n\npart1 = one").unwrap_err();
+        assert_eq!(err.message, "line 3: invalid answer");
+    }
+
+    #[test]
+    fn display_roundtrips() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+        let s = answers.to_string();
+
+        assert_eq!(
+            s,
+            "[01]\npart1 = 138\npart2 = 1771\n\n[21]\npart2 = \"vv,nlxsmb # \\\"x\\\"\"\n"
+        );
+        assert_eq!(Answers::parse(&s), Ok(answers));
+    }
+
+    #[test]
+    fn verify_works() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+        let report = |day, answer: &str, status| SolveReport {
+            year: 2015,
+            day,
+            part: Part::One,
+            answer: answer.into(),
+            parse: Duration::default(),
+            duration: Duration::default(),
+            allocs: None,
+            status,
+        };
+
+        assert_eq!(
+            answers.verify(&report(1, "138", Status::Solved)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verify(&report(1, "139", Status::Solved)),
+            Verdict::Fail
+        );
+        assert_eq!(
+            answers.verify(&report(1, "138", Status::NoAnswer)),
+            Verdict::Fail
+        );
+        assert_eq!(
+            answers.verify(&report(2, "1", Status::Solved)),
+            Verdict::Missing
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{Grid, NEIGHBOURS4, NEIGHBOURS8};
+
+/// Where the neighbours of a cell are. Implemented by every function which
+/// returns the neighbours of a cell, like [moore] or a closure.
+pub trait Topology<C> {
+    type Neighbours: Iterator<Item = C>;
+
+    fn neighbours(&self, cell: C) -> Self::Neighbours;
+}
+
+impl<C, F, I> Topology<C> for F
+where
+    F: Fn(C) -> I,
+    I: IntoIterator<Item = C>,
+{
+    type Neighbours = I::IntoIter;
+
+    fn neighbours(&self, cell: C) -> Self::Neighbours {
+        self(cell).into_iter()
+    }
+}
+
+/// The four cells which share a side with a cell on a square grid.
+pub fn von_neumann((x, y): (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+    NEIGHBOURS4.iter().map(move |&(dx, dy)| (x + dx, y + dy))
+}
+
+/// The eight cells which share a side or corner with a cell on a square grid.
+pub fn moore((x, y): (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+    NEIGHBOURS8.iter().map(move |&(dx, dy)| (x + dx, y + dy))
+}
+
+/// Decides whether a cell is alive in the next generation from whether it is
+/// alive now and how many of its neighbours are. Implemented by every
+/// `Fn(bool, usize) -> bool`.
+pub trait Rule {
+    fn next(&self, alive: bool, neighbours: usize) -> bool;
+}
+
+impl<F: Fn(bool, usize) -> bool> Rule for F {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        self(alive, neighbours)
+    }
+}
+
+/// A rule which brings a dead cell to life or keeps a living cell alive when
+/// the amount of living neighbours is one of the given amounts, like
+/// [LifeLike::CONWAY].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LifeLike {
+    birth: u64,
+    survival: u64,
+}
+
+impl LifeLike {
+    /// The rule of Conway's Game of Life, where a dead cell with 3 living
+    /// neighbours comes to life and a living cell with 2 or 3 stays alive.
+    pub const CONWAY: Self = Self::new(&[3], &[2, 3]);
+
+    /// # Panics
+    ///
+    /// Panics if an amount is 64 or more.
+    pub const fn new(birth: &[usize], survival: &[usize]) -> Self {
+        Self {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+}
+
+const fn mask(amounts: &[usize]) -> u64 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < amounts.len() {
+        assert!(amounts[i] < 64, "life-like rules count up to 63 neighbours");
+        mask |= 1 << amounts[i];
+        i += 1;
+    }
+    mask
+}
+
+impl Rule for LifeLike {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        let amounts = if alive { self.survival } else { self.birth };
+        neighbours < 64 && amounts & 1 << neighbours != 0
+    }
+}
+
+/// How an [Automaton] stores its cells and computes the next generation.
+pub trait Backend {
+    /// Replaces every cell with its state in the next generation.
+    fn step(&mut self);
+
+    /// Returns the amount of living cells.
+    fn population(&self) -> usize;
+}
+
+/// A backend which only stores the living cells, for unbounded spaces where
+/// few cells are alive. Cells can be anything hashable, like points of any
+/// dimension.
+#[derive(Debug, Clone)]
+pub struct Sparse<C, T, R> {
+    cells: HashSet<C>,
+    topology: T,
+    rule: R,
+}
+
+impl<C, T, R> Sparse<C, T, R> {
+    /// Returns the living cells.
+    pub fn cells(&self) -> &HashSet<C> {
+        &self.cells
+    }
+}
+
+impl<C, T, R> Backend for Sparse<C, T, R>
+where
+    C: Copy + Eq + Hash,
+    T: Topology<C>,
+    R: Rule,
+{
+    fn step(&mut self) {
+        // Only living cells and their neighbours can be alive next
+        let mut counts = HashMap::with_capacity(self.cells.len() * 2);
+        for &cell in &self.cells {
+            counts.entry(cell).or_insert(0);
+            for neighbour in self.topology.neighbours(cell) {
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        self.cells = counts
+            .into_iter()
+            .filter(|&(cell, count)| self.rule.next(self.cells.contains(&cell), count))
+            .map(|(cell, _)| cell)
+            .collect();
+    }
+
+    fn population(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// A backend which stores every cell of a bounded grid, for spaces where
+/// many cells are alive. Cells outside of the grid are always dead.
+#[derive(Debug, Clone)]
+pub struct Dense<T, R> {
+    grid: Grid<bool>,
+    topology: T,
+    rule: R,
+}
+
+impl<T, R> Dense<T, R> {
+    /// Returns every cell, which is `true` if it is alive.
+    pub fn grid(&self) -> &Grid<bool> {
+        &self.grid
+    }
+}
+
+impl<T, R> Backend for Dense<T, R>
+where
+    T: Topology<(isize, isize)>,
+    R: Rule,
+{
+    fn step(&mut self) {
+        let grid = &self.grid;
+        let next = Grid::from_fn(grid.width(), grid.height(), |x, y| {
+            let neighbours = self
+                .topology
+                .neighbours((x as isize, y as isize))
+                .filter(|&(x, y)| grid.contains(x, y) && grid[(x as usize, y as usize)])
+                .count();
+            self.rule.next(grid[(x, y)], neighbours)
+        });
+        self.grid = next;
+    }
+
+    fn population(&self) -> usize {
+        self.grid.iter().filter(|&&alive| alive).count()
+    }
+}
+
+/// A cellular automaton which applies a [Rule] to every cell at once each
+/// generation, counting the living neighbours given by a [Topology]. Keeps the
+/// population of every generation.
+///
+/// ```
+/// use aoc_lib::{moore, Automaton, Grid, LifeLike};
+///
+/// let blinker = Grid::parse("...\n###\n...", |c| Some(c == '#')).unwrap();
+/// let mut life = Automaton::dense(blinker, moore, LifeLike::CONWAY);
+///
+/// life.step();
+/// assert_eq!(life.backend().grid().column(1).filter(|&&alive| alive).count(), 3);
+/// assert_eq!(life.history(), [3, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Automaton<B> {
+    backend: B,
+    history: Vec<usize>,
+}
+
+impl<C, T, R> Automaton<Sparse<C, T, R>>
+where
+    C: Copy + Eq + Hash,
+    T: Topology<C>,
+    R: Rule,
+{
+    /// Creates an automaton with the given living cells in an unbounded
+    /// space, see [Sparse].
+    pub fn sparse(cells: impl IntoIterator<Item = C>, topology: T, rule: R) -> Self {
+        Self::new(Sparse {
+            cells: cells.into_iter().collect(),
+            topology,
+            rule,
+        })
+    }
+}
+
+impl<T, R> Automaton<Dense<T, R>>
+where
+    T: Topology<(isize, isize)>,
+    R: Rule,
+{
+    /// Creates an automaton with the living cells of a bounded grid, see
+    /// [Dense].
+    pub fn dense(grid: Grid<bool>, topology: T, rule: R) -> Self {
+        Self::new(Dense {
+            grid,
+            topology,
+            rule,
+        })
+    }
+}
+
+impl<B: Backend> Automaton<B> {
+    pub fn new(backend: B) -> Self {
+        let history = vec![backend.population()];
+        Self { backend, history }
+    }
+
+    /// Advances the automaton by one generation.
+    pub fn step(&mut self) {
+        self.backend.step();
+        self.history.push(self.backend.population());
+    }
+
+    /// Advances the automaton by the given amount of generations.
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Returns the amount of generations which have passed.
+    pub fn generation(&self) -> usize {
+        self.history.len() - 1
+    }
+
+    /// Returns the amount of living cells.
+    pub fn population(&self) -> usize {
+        self.backend.population()
+    }
+
+    /// Returns the population of every generation, starting with the initial
+    /// one.
+    pub fn history(&self) -> &[usize] {
+        &self.history
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLIDER: &str = ".#....\n..#...\n###...\n......\n......\n......";
+
+    fn glider() -> Grid<bool> {
+        Grid::parse(GLIDER, |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn life_like_works() {
+        assert!(LifeLike::CONWAY.next(false, 3));
+        assert!(!LifeLike::CONWAY.next(false, 2));
+        assert!(LifeLike::CONWAY.next(true, 2));
+        assert!(!LifeLike::CONWAY.next(true, 4));
+        assert!(!LifeLike::CONWAY.next(true, 242));
+    }
+
+    #[test]
+    fn backends_agree() {
+        let cells = glider()
+            .positions()
+            .filter(|(_, &alive)| alive)
+            .map(|((x, y), _)| (x as isize, y as isize))
+            .collect::<Vec<_>>();
+        let mut sparse = Automaton::sparse(cells, moore, LifeLike::CONWAY);
+        let mut dense = Automaton::dense(glider(), moore, LifeLike::CONWAY);
+
+        sparse.run(4);
+        dense.run(4);
+        assert_eq!(sparse.generation(), 4);
+        assert_eq!(sparse.history(), [5; 5]);
+        assert_eq!(dense.history(), sparse.history());
+
+        // A glider moves one cell down and right every 4 generations
+        let mut moved: Vec<_> = sparse.backend().cells().iter().copied().collect();
+        moved.sort_unstable_by_key(|&(x, y)| (y, x));
+        assert_eq!(moved, [(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]);
+        assert!(moved
+            .iter()
+            .all(|&(x, y)| dense.backend().grid()[(x as usize, y as usize)]));
+    }
+
+    #[test]
+    fn dense_cells_die_at_the_edges() {
+        let mut dense = Automaton::dense(glider(), moore, LifeLike::CONWAY);
+        dense.run(40);
+        // The glider turns into a block in the bottom right corner
+        assert_eq!(dense.population(), 4);
+    }
+
+    #[test]
+    fn rules_can_be_closures() {
+        // Every cell with a living neighbour comes to life
+        let mut grow = Automaton::sparse(vec![(0, 0)], von_neumann, |alive, neighbours| {
+            alive || neighbours > 0
+        });
+        grow.run(2);
+        assert_eq!(grow.history(), [1, 5, 13]);
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::{Part, Result, Solver};
+
+/// How long and how often a [Solver](Solver) is run by
+/// [Solver::bench](Solver::bench).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Time spent on runs which are not measured, to warm up caches and the
+    /// branch predictor. At least one warm-up run is always done.
+    pub warm_up: Duration,
+    /// Time after which no new measured run is started.
+    pub budget: Duration,
+    /// Minimum amount of measured runs, even if the budget is exceeded.
+    pub min_runs: usize,
+    /// Maximum amount of measured runs, even if budget is left.
+    pub max_runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(500),
+            budget: Duration::from_secs(3),
+            min_runs: 5,
+            max_runs: 10_000,
+        }
+    }
+}
+
+/// Statistics of the measured runs of a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The sample standard deviation.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculates the statistics of the given run durations, or `None` if
+    /// there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let runs = sorted.len();
+        // Both indices are the same for an odd amount of runs
+        let median = (sorted[(runs - 1) / 2] + sorted[runs / 2]) / 2;
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.3?}  median {:>10.3?}  mean {:>10.3?}  stddev {:>10.3?}  ({} runs)",
+            self.min, self.median, self.mean, self.stddev, self.runs
+        )
+    }
+}
+
+/// Statistics of parsing and both parts of a solution, see
+/// [Entry::bench](crate::Entry::bench).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchReport {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchReport {
+    pub fn part(&self, part: Part) -> &Stats {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+}
+
+/// Runs the solver as described by the config, see
+/// [Solver::bench](Solver::bench).
+pub(crate) fn bench<S: Solver + ?Sized>(
+    solver: &S,
+    input: &str,
+    config: &BenchConfig,
+) -> Result<Stats> {
+    bench_with(
+        || {
+            let (ans, elapsed) = solver.time(input);
+            (ans.map(drop), elapsed)
+        },
+        config,
+    )
+}
+
+/// Repeats the timed run as described by the config. Fails on the first run
+/// which fails.
+pub(crate) fn bench_with<F>(mut run: F, config: &BenchConfig) -> Result<Stats>
+where
+    F: FnMut() -> (Result<()>, Duration),
+{
+    let start = Instant::now();
+    loop {
+        run().0?;
+        if start.elapsed() >= config.warm_up {
+            break;
+        }
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.len() < config.max_runs.max(config.min_runs)
+        && (samples.len() < config.min_runs || start.elapsed() < config.budget)
+    {
+        let (result, elapsed) = run();
+        result?;
+        samples.push(elapsed);
+    }
+
+    Ok(Stats::from_samples(&samples).expect("at least one measured run"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn stats_from_samples_works() {
+        let samples: Vec<_> = [4, 1, 3, 2]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        // sqrt(5 / 3) ms
+        assert_eq!(stats.stddev.as_micros(), 1290);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    struct Counter(Cell<usize>);
+
+    impl Solver for Counter {
+        type Answer = usize;
+
+        fn solve(&self, _: &str) -> Result<Self::Answer> {
+            self.0.set(self.0.get() + 1);
+            match self.0.get() {
+                100 => Err(Error::no_answer("tired")),
+                count => Ok(count),
+            }
+        }
+    }
+
+    #[test]
+    fn bench_respects_run_limits() {
+        let config = BenchConfig {
+            warm_up: Duration::from_secs(0),
+            budget: Duration::from_secs(0),
+            min_runs: 3,
+            max_runs: 10,
+        };
+        let counter = Counter(Cell::new(0));
+        assert_eq!(counter.bench("", &config).unwrap().runs, 3);
+        // Warm-up run included
+        assert_eq!(counter.0.get(), 4);
+
+        let config = BenchConfig {
+            budget: Duration::from_secs(60),
+            ..config
+        };
+        assert_eq!(counter.bench("", &config).unwrap().runs, 10);
+
+        let config = BenchConfig {
+            max_runs: 1000,
+            ..config
+        };
+        assert!(counter.bench("", &config).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{Error, Result};
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// A flag which asks a running solution to stop.
+///
+/// Cancellation is cooperative: long running loops poll
+/// [check_cancelled](check_cancelled) which fails once the token of the
+/// current thread was cancelled, for example by [watch](watch).
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Makes this the token of the current thread until `f` returns.
+    pub fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        let prev = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let result = f();
+        CURRENT.with(|current| current.replace(prev));
+        result
+    }
+}
+
+/// Returns whether the token of the current thread was cancelled. Always false
+/// outside of [CancelToken::scope](CancelToken::scope).
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+    })
+}
+
+/// Fails with a timeout error if the token of the current thread was
+/// cancelled. Meant to be polled by long running loops.
+pub fn check_cancelled() -> Result<()> {
+    if cancelled() {
+        Err(Error::timeout("cancelled"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs `f` on its own thread under a watchdog which fails with a timeout
+/// error once `limit` has passed.
+///
+/// The token of the thread is cancelled on a timeout, a solution which does not
+/// poll it keeps running in the background until it finishes.
+///
+/// # Panics
+///
+/// Panics if `f` panicked.
+pub fn watch<T, F>(limit: Duration, f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    let token = CancelToken::default();
+    let (tx, rx) = mpsc::channel();
+
+    let worker = token.clone();
+    thread::spawn(move || {
+        // The receiver is gone if the watchdog gave up already
+        let _ = tx.send(worker.scope(f));
+    });
+
+    match rx.recv_timeout(limit) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Err(Error::timeout(format!("took longer than {:?}", limit)))
+        }
+        Err(RecvTimeoutError::Disconnected) => panic!("solution panicked"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn scope_installs_token() {
+        let token = CancelToken::default();
+        assert!(!cancelled());
+
+        token.scope(|| {
+            assert_eq!(check_cancelled(), Ok(()));
+            token.cancel();
+            assert!(cancelled());
+        });
+        assert!(!cancelled());
+    }
+
+    #[test]
+    fn watch_passes_result() {
+        assert_eq!(watch(Duration::from_secs(60), || Ok(42)), Ok(42));
+        assert!(watch::<(), _>(Duration::from_secs(60), || Err(Error::no_answer(""))).is_err());
+    }
+
+    #[test]
+    fn watch_times_out() {
+        let (tx, rx) = mpsc::channel();
+        let result = watch(Duration::from_millis(10), move || loop {
+            if let Err(err) = check_cancelled() {
+                tx.send(()).unwrap();
+                return Err::<(), _>(err);
+            }
+            thread::sleep(Duration::from_millis(1));
+        });
+
+        assert_eq!(result.unwrap_err().kind, ErrorKind::Timeout);
+        // The loop noticed the cancellation
+        rx.recv_timeout(Duration::from_secs(60)).unwrap();
+    }
+}
@@ -0,0 +1,349 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    answers::{parse_value, strip_comment},
+    Error, Outcome, Part, Result,
+};
+
+/// Settings of the [Client](Client), read from a config file in which only
+/// `key = "value"` lines are supported:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// url = "https://adventofcode.com"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    /// The base URL of the website, without a trailing slash.
+    pub url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            url: Self::DEFAULT_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// The website which is used when no other URL was configured.
+    pub const DEFAULT_URL: &'static str = "https://adventofcode.com";
+
+    /// Reads the config file at `$AOC_CONFIG`, or `aoc/config.toml` in the
+    /// config directory of the user. The `AOC_SESSION` and `AOC_URL`
+    /// environment variables take precedence over the file. A missing file is
+    /// the default config.
+    pub fn load() -> io::Result<Self> {
+        let mut config = match Self::path() {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(s) => Self::parse(&s).map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: {}", path.display(), err),
+                    )
+                })?,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+                Err(err) => return Err(err),
+            },
+            None => Self::default(),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(url) = env::var("AOC_URL") {
+            config.url = url;
+        }
+        Ok(config)
+    }
+
+    /// Returns where [Config::load](Config::load) looks for the config file.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os("AOC_CONFIG") {
+            return Some(path.into());
+        }
+
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+            .map(|dir| dir.join("aoc").join("config.toml"))
+    }
+
+    /// Parses the contents of a config file.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut config = Self::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let invalid = |msg: &str| Error::parse(format!("line {}: {}", i + 1, msg));
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| invalid("expected `key = \"value\"`"))?;
+            let value = parse_value(value.trim()).ok_or_else(|| invalid("invalid value"))?;
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "url" => config.url = value,
+                key => return Err(invalid(&format!("unknown key `{}`", key))),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// A client of the Advent of Code website which is logged in with the session
+/// cookie of a browser.
+pub struct Client {
+    url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Creates a client for the configured website. Fails if no session was
+    /// configured.
+    pub fn new(config: &Config) -> io::Result<Self> {
+        let session = config.session.clone().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "no session token, set `AOC_SESSION` or `session` in the config file",
+            )
+        })?;
+
+        let agent = ureq::AgentBuilder::new()
+            .user_agent("github.com/aod/advent-of-rust by oktay@oktaydinler.xyz")
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Ok(Self {
+            url: config.url.trim_end_matches('/').to_string(),
+            session,
+            agent,
+        })
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> io::Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.url, year, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        response_text(response, year, day)
+    }
+
+    /// Submits the answer of a part and returns how the website judged it.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> io::Result<Outcome> {
+        let url = format!("{}/{}/day/{}/answer", self.url, year, day);
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", level), ("answer", answer)]);
+
+        let html = response_text(response, year, day)?;
+        Outcome::parse(&html).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unexpected response from {}", url),
+            )
+        })
+    }
+
+    /// Downloads the puzzle input of a day into `NN.txt` in the given
+    /// directory unless it is cached there already. Returns whether the input
+    /// was downloaded.
+    pub fn fetch_input(&self, year: u16, day: u8, dir: &Path) -> io::Result<bool> {
+        let path = dir.join(format!("{:02}.txt", day));
+        if path.exists() {
+            return Ok(false);
+        }
+
+        let input = self.input(year, day)?;
+        fs::create_dir_all(dir)?;
+        // A failed download never leaves a partial input behind
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, input)?;
+        fs::rename(partial, path)?;
+        Ok(true)
+    }
+}
+
+/// Returns the body of a successful response, or an error describing the
+/// failed request.
+fn response_text(
+    response: std::result::Result<ureq::Response, ureq::Error>,
+    year: u16,
+    day: u8,
+) -> io::Result<String> {
+    match response {
+        Ok(response) => response.into_string(),
+        Err(ureq::Error::Status(404, _)) => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} day {:02} is not available yet", year, day),
+        )),
+        Err(ureq::Error::Status(status, response)) => {
+            let url = response.get_url().to_string();
+            let body = response.into_string().unwrap_or_default();
+            Err(io::Error::other(format!(
+                "{} returned {}: {}",
+                url,
+                status,
+                body.lines().next().unwrap_or_default()
+            )))
+        }
+        Err(err) => Err(io::Error::other(err.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    use super::*;
+
+    /// Serves the given responses on a local port, one per connection, and
+    /// returns the base URL together with the received requests.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut head = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let mut request_body = vec![0; length];
+                std::io::Read::read_exact(&mut reader, &mut request_body).unwrap();
+                head.push_str(&String::from_utf8(request_body).unwrap());
+                tx.send(head).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    fn client(url: String) -> Client {
+        Client::new(&Config {
+            session: Some("cafe".into()),
+            url,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn config_parse_works() {
+        let config = Config::parse("# mine\nsession = \"cafe\"\nurl = \"http://localhost\"\n");
+        assert_eq!(
+            config,
+            Ok(Config {
+                session: Some("cafe".into()),
+                url: "http://localhost".into()
+            })
+        );
+
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+        assert!(Config::parse("token = \"cafe\"").is_err());
+        assert!(Config::parse("session = cafe").is_err());
+    }
+
+    #[test]
+    fn client_requires_session() {
+        assert!(Client::new(&Config::default()).is_err());
+    }
+
+    #[test]
+    fn input_works() {
+        let (url, requests) = serve(vec![(200, "1\n2\n"), (404, "Not Found")]);
+        let client = client(url);
+
+        assert_eq!(client.input(2020, 9).unwrap(), "1\n2\n");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2020/day/9/input HTTP/1.1"));
+        assert!(request.contains("session=cafe"));
+
+        let err = client.input(2020, 25).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn submit_works() {
+        let (url, requests) = serve(vec![
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (200, "<article><p>Log in</p></article>"),
+        ]);
+        let client = client(url);
+
+        assert_eq!(
+            client.submit(2020, 9, Part::Two, "a b").unwrap(),
+            Outcome::Correct
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2020/day/9/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=a+b"));
+
+        let err = client.submit(2020, 9, Part::One, "1").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn fetch_input_caches() {
+        let dir = env::temp_dir().join("aoc-lib-fetch-input-caches");
+        let _ = fs::remove_dir_all(&dir);
+
+        // A second request would never be answered
+        let (url, requests) = serve(vec![(200, "abc\n")]);
+        let client = client(url);
+
+        assert!(client.fetch_input(2015, 4, &dir).unwrap());
+        assert!(!client.fetch_input(2015, 4, &dir).unwrap());
+        assert_eq!(fs::read_to_string(dir.join("04.txt")).unwrap(), "abc\n");
+        assert_eq!(requests.try_iter().count(), 1);
+    }
+}
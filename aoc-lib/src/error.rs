@@ -0,0 +1,139 @@
+use std::{
+    error,
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+use crate::Part;
+
+/// The kind of an [Error](Error).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The puzzle input is malformed.
+    Parse,
+    /// The puzzle input is well formed but it has no answer.
+    NoAnswer,
+    /// The solution ran out of time, see [watch](crate::watch).
+    Timeout,
+    /// A parameter of the solution is unknown or has an invalid value, see
+    /// [Params](crate::Params).
+    Param,
+}
+
+/// An error which occurred while solving a part of a puzzle.
+///
+/// Solutions only know what went wrong, the day and part are filled in by
+/// whoever runs the solution, see [Error::at](Error::at).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Creates an error for malformed puzzle input.
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse, message.into())
+    }
+
+    /// Creates an error for puzzle input which has no answer.
+    pub fn no_answer(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::NoAnswer, message.into())
+    }
+
+    /// Creates an error for a solution which ran out of time.
+    pub fn timeout(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Timeout, message.into())
+    }
+
+    /// Creates an error for an unknown or invalid parameter.
+    pub fn param(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Param, message.into())
+    }
+
+    fn new(kind: ErrorKind, message: String) -> Self {
+        Self {
+            kind,
+            day: None,
+            part: None,
+            message,
+        }
+    }
+
+    /// Sets the day in which the error occurred, unless it was already set.
+    /// Used for errors which do not belong to a single part, like parse errors
+    /// of a [Solution](crate::Solution).
+    pub fn at_day(mut self, day: u8) -> Self {
+        self.day = self.day.or(Some(day));
+        self
+    }
+
+    /// Sets the day and part in which the error occurred, unless they were
+    /// already set.
+    pub fn at(mut self, day: u8, part: Part) -> Self {
+        self.day = self.day.or(Some(day));
+        self.part = self.part.or(Some(part));
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {:02} {}: ", day, part)?,
+            (Some(day), None) => write!(f, "day {:02}: ", day)?,
+            (None, Some(part)) => write!(f, "{}: ", part)?,
+            (None, None) => {}
+        }
+
+        match self.kind {
+            ErrorKind::Parse => write!(f, "invalid input: {}", self.message),
+            ErrorKind::NoAnswer => write!(f, "no answer: {}", self.message),
+            ErrorKind::Timeout => write!(f, "timed out: {}", self.message),
+            ErrorKind::Param => write!(f, "invalid parameter: {}", self.message),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Self::parse(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_works() {
+        assert_eq!(
+            Error::parse("expected a number").to_string(),
+            "invalid input: expected a number"
+        );
+        assert_eq!(
+            Error::no_answer("wire a does not exist")
+                .at(7, Part::One)
+                .to_string(),
+            "day 07 Part1: no answer: wire a does not exist"
+        );
+    }
+
+    #[test]
+    fn at_keeps_first_location() {
+        let err = Error::parse("").at(7, Part::One).at(8, Part::Two);
+        assert_eq!((err.day, err.part), (Some(7), Some(Part::One)));
+    }
+
+    #[test]
+    fn from_parse_int_error() {
+        let err: Error = "x".parse::<u8>().unwrap_err().into();
+        assert_eq!(err.kind, ErrorKind::Parse);
+    }
+}
@@ -0,0 +1,477 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Bound, Index, IndexMut, Range, RangeBounds},
+};
+
+use crate::{Result, Scanner};
+
+/// The offsets of the neighbours of a cell which share a side with it,
+/// clockwise starting from the cell above.
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the neighbours of a cell which share a side or corner with
+/// it, clockwise starting from the cell above.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangle of cells stored row by row, indexed by `(x, y)` where `x` is the
+/// column and `y` the row starting from the top left.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of cells is not a multiple of the width.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width,
+        };
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells do not fit rows of {}",
+            cells.len(),
+            width
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid with the cell of every `(x, y)` position returned by `f`.
+    /// The grid keeps its width and height even if one of them is zero.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a map of characters, one row per line, with `f` turning a
+    /// character into a cell or `None` if it is not a valid cell. Fails with the
+    /// position of the first invalid character or a row of a different width.
+    ///
+    /// ```
+    /// let grid = aoc_lib::Grid::parse(".#.\n##.\n", |c| match c {
+    ///     '#' => Some(true),
+    ///     '.' => Some(false),
+    ///     _ => None,
+    /// })
+    /// .unwrap();
+    ///
+    /// assert_eq!((grid.width(), grid.height()), (3, 2));
+    /// assert_eq!(grid[(1, 0)], true);
+    /// ```
+    pub fn parse(s: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Scanner::new(s).grid(f)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns whether the position lies within the grid.
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(move |index| &mut self.cells[index])
+    }
+
+    /// Returns the cell at the position wrapped around the edges, so the grid
+    /// repeats in every direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Returns every cell row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns every cell with its position row by row.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    /// Returns the positions of the neighbours of a cell which share a side
+    /// with it and lie within the grid, see [NEIGHBOURS4].
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS4)
+    }
+
+    /// Returns the positions of the neighbours of a cell which share a side or
+    /// corner with it and lie within the grid, see [NEIGHBOURS8].
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS8)
+    }
+
+    fn neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            self.contains(x, y).then_some((x as usize, y as usize))
+        })
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[self.rows_of(y..=y)]
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        let rows = self.rows_of(y..=y);
+        &mut self.cells[rows]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Not `chunks`, which panics on a width of 0 and skips the empty rows
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the column is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Returns the rows of the rectangle spanned by the given columns and rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle does not lie within the grid.
+    pub fn rect(
+        &self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> impl Iterator<Item = &[T]> {
+        let xs = bounds(xs, self.width);
+        self.cells[self.rows_of(ys)]
+            .chunks(self.width.max(1))
+            .map(move |row| &row[xs.clone()])
+    }
+
+    /// Like [Grid::rect], but the cells can be changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle does not lie within the grid.
+    pub fn rect_mut(
+        &mut self,
+        xs: impl RangeBounds<usize>,
+        ys: impl RangeBounds<usize>,
+    ) -> impl Iterator<Item = &mut [T]> {
+        let xs = bounds(xs, self.width);
+        let (rows, width) = (self.rows_of(ys), self.width.max(1));
+        self.cells[rows]
+            .chunks_mut(width)
+            .map(move |row| &mut row[xs.clone()])
+    }
+
+    /// Returns the range of cells of the given rows.
+    fn rows_of(&self, ys: impl RangeBounds<usize>) -> Range<usize> {
+        let ys = bounds(ys, self.height);
+        ys.start * self.width..ys.end * self.width
+    }
+
+    /// Creates a grid of the same size with every cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid from left to right.
+    pub fn flip_horizontal(&mut self) {
+        for row in self.cells.chunks_mut(self.width.max(1)) {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the grid upside down.
+    pub fn flip_vertical(&mut self) {
+        for y in 0..self.height / 2 {
+            let (top, bottom) = self.cells.split_at_mut((self.height - y - 1) * self.width);
+            top[y * self.width..(y + 1) * self.width].swap_with_slice(&mut bottom[..self.width]);
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to the value.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::from_fn(width, height, |_, _| value.clone())
+    }
+
+    /// Mirrors the grid along the diagonal from the top left, which turns its
+    /// rows into columns. Square grids are mirrored in place.
+    pub fn transpose(&mut self) {
+        if self.width == self.height {
+            let n = self.width;
+            for y in 0..n {
+                for x in y + 1..n {
+                    self.cells.swap(y * n + x, x * n + y);
+                }
+            }
+        } else {
+            *self = Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone());
+        }
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_right(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_left(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+}
+
+/// Converts the range to the indices of a dimension of the given length.
+///
+/// # Panics
+///
+/// Panics if the range does not lie within the dimension.
+fn bounds(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "{}..{} is out of bounds of {}",
+        start,
+        end,
+        len
+    );
+    start..end
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is out of bounds of {}x{}",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is out of bounds of {}x{}", x, y, width, height))
+    }
+}
+
+/// Shows every cell, a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Grid<u32> {
+        Grid::parse(s, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_works() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let err = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.message, "line 2, column 2: unexpected `x`");
+        let err = Grid::parse("12\n345\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.message, "line 2: expected 2 cells, found 3");
+
+        let empty = Grid::parse("", |c| c.to_digit(10)).unwrap();
+        assert_eq!(
+            (empty.width(), empty.height(), empty.rows().count()),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn access_works() {
+        let mut grid = digits("123\n456");
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert!(grid.contains(0, 1) && !grid.contains(-1, 0));
+        assert_eq!(grid.get_wrapping(-1, 2), &3);
+        assert_eq!(grid.get_wrapping(4, -1), &5);
+
+        grid[(0, 0)] = 7;
+        *grid.get_mut(1, 0).unwrap() = 8;
+        assert_eq!(grid.row(0), [7, 8, 3]);
+        assert_eq!(grid.positions().last(), Some(((2, 1), &6)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_is_bounds_checked() {
+        let _ = digits("12\n34")[(2, 0)];
+    }
+
+    #[test]
+    fn neighbours_works() {
+        let grid = digits("123\n456\n789");
+        let values = |positions: Vec<(usize, usize)>| -> Vec<u32> {
+            positions.into_iter().map(|pos| grid[pos]).collect()
+        };
+
+        assert_eq!(values(grid.neighbours4(1, 1).collect()), [2, 6, 8, 4]);
+        assert_eq!(values(grid.neighbours4(0, 0).collect()), [2, 4]);
+        assert_eq!(
+            values(grid.neighbours8(1, 1).collect()),
+            [2, 3, 6, 9, 8, 7, 4, 1]
+        );
+        assert_eq!(values(grid.neighbours8(2, 2).collect()), [6, 8, 5]);
+    }
+
+    #[test]
+    fn views_work() {
+        let mut grid = digits("123\n456\n789");
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5, 8]);
+        assert_eq!(grid.rect(1.., ..2).collect::<Vec<_>>(), [&[2, 3], &[5, 6]]);
+
+        for row in grid.rect_mut(0..=1, 1..=2) {
+            row.iter_mut().for_each(|cell| *cell = 0);
+        }
+        assert_eq!(grid.to_string(), "123\n006\n009\n");
+        assert_eq!(
+            grid.map(|&cell| cell > 0).iter().filter(|&&on| on).count(),
+            5
+        );
+    }
+
+    #[test]
+    fn orientations_work() {
+        let mut grid = digits("123\n456");
+
+        grid.rotate_right();
+        assert_eq!(grid.to_string(), "41\n52\n63\n");
+        grid.rotate_left();
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        grid.transpose();
+        assert_eq!(grid.to_string(), "14\n25\n36\n");
+        grid.transpose();
+
+        grid.flip_horizontal();
+        assert_eq!(grid.to_string(), "321\n654\n");
+        grid.flip_vertical();
+        assert_eq!(grid.to_string(), "654\n321\n");
+
+        let mut odd = digits("1\n2\n3");
+        odd.flip_vertical();
+        assert_eq!(odd.to_string(), "3\n2\n1\n");
+
+        let mut square = digits("12\n34");
+        square.rotate_right();
+        assert_eq!(square.to_string(), "31\n42\n");
+        square.rotate_left();
+        square.rotate_left();
+        assert_eq!(square.to_string(), "24\n13\n");
+        square.transpose();
+        assert_eq!(square.to_string(), "21\n43\n");
+
+        let mut empty = Grid::<u8>::from_fn(0, 3, |_, _| 0);
+        assert_eq!((empty.width(), empty.height()), (0, 3));
+        assert_eq!(empty.to_string(), "\n\n\n");
+        empty.transpose();
+        assert_eq!((empty.width(), empty.height()), (3, 0));
+        empty.rotate_right();
+        assert_eq!((empty.width(), empty.height()), (0, 3));
+    }
+}
@@ -0,0 +1,405 @@
+//! Hexagonal grids in cube, axial and offset coordinates, following
+//! <https://www.redblobgames.com/grids/hexagons/>.
+//!
+//! ```
+//! use aoc_lib::hex::{Cube, Pointy};
+//!
+//! let tile = Cube::ORIGIN + Pointy::E.offset() * 2 + Pointy::NW.offset();
+//! assert_eq!(tile.distance(Cube::ORIGIN), 2);
+//! assert_eq!(tile.rotate_left().rotate_right(), tile);
+//! assert_eq!(Cube::ORIGIN.spiral(2).count(), 19);
+//! ```
+
+use std::{
+    fmt::{self, Display},
+    iter::{self, Sum},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{Error, Result};
+
+/// A hex in cube coordinates, whose `q`, `r` and `s` always add up to 0. `q`
+/// grows towards the east, `r` towards the south and `s` towards the north
+/// west on a pointy topped grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cube {
+    q: isize,
+    r: isize,
+    s: isize,
+}
+
+/// The offsets of the six neighbours of a hex, counter clockwise starting from
+/// the east on a pointy topped grid and from the south east on a flat topped
+/// one.
+const DIRECTIONS: [Cube; 6] = [
+    Cube::new(1, 0),
+    Cube::new(1, -1),
+    Cube::new(0, -1),
+    Cube::new(-1, 0),
+    Cube::new(-1, 1),
+    Cube::new(0, 1),
+];
+
+impl Cube {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    /// Creates the hex at `q` and `r`, `s` follows from them.
+    pub const fn new(q: isize, r: isize) -> Self {
+        Self { q, r, s: -q - r }
+    }
+
+    pub fn q(self) -> isize {
+        self.q
+    }
+
+    pub fn r(self) -> isize {
+        self.r
+    }
+
+    pub fn s(self) -> isize {
+        self.s
+    }
+
+    /// Returns the amount of steps between the hexes.
+    pub fn distance(self, other: Self) -> usize {
+        let d = self - other;
+        (d.q.unsigned_abs() + d.r.unsigned_abs() + d.s.unsigned_abs()) / 2
+    }
+
+    /// Returns the six hexes which share a side with this hex.
+    pub fn neighbours(self) -> impl ExactSizeIterator<Item = Self> {
+        DIRECTIONS.iter().map(move |&offset| self + offset)
+    }
+
+    /// Returns the `6 * radius` hexes at the given distance, or only this hex
+    /// if the radius is 0.
+    pub fn ring(self, radius: usize) -> impl Iterator<Item = Self> {
+        let start = self + DIRECTIONS[4] * radius as isize;
+        let sides = DIRECTIONS
+            .iter()
+            .flat_map(move |&offset| iter::repeat_n(offset, radius))
+            .scan(start, |hex, offset| {
+                let current = *hex;
+                *hex += offset;
+                Some(current)
+            });
+        iter::once(self).filter(move |_| radius == 0).chain(sides)
+    }
+
+    /// Returns the hexes up to the given distance, ring by ring from the
+    /// center outwards.
+    pub fn spiral(self, radius: usize) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |radius| self.ring(radius))
+    }
+
+    /// Rotates the hex 60° clockwise around the origin. Subtract and add a
+    /// center to rotate around it instead.
+    pub fn rotate_right(self) -> Self {
+        Self {
+            q: -self.r,
+            r: -self.s,
+            s: -self.q,
+        }
+    }
+
+    /// Rotates the hex 60° counter clockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self {
+            q: -self.s,
+            r: -self.q,
+            s: -self.r,
+        }
+    }
+
+    /// Returns the hexes on the straight line from this hex to the other,
+    /// including both.
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = Self> {
+        let n = self.distance(other);
+        (0..=n).map(move |i| {
+            let t = if n == 0 { 0.0 } else { i as f64 / n as f64 };
+            // Nudge the line off of the edges between hexes so that it never
+            // has to pick between two of them
+            let lerp = |a: isize, b: isize, nudge: f64| a as f64 + (b - a) as f64 * t + nudge;
+            Self::round(
+                lerp(self.q, other.q, 1e-6),
+                lerp(self.r, other.r, 2e-6),
+                lerp(self.s, other.s, -3e-6),
+            )
+        })
+    }
+
+    /// Returns the hex which contains the given fractional cube coordinates.
+    fn round(q: f64, r: f64, s: f64) -> Self {
+        let (rq, rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            Self::new(-(rr + rs) as isize, rr as isize)
+        } else if dr > ds {
+            Self::new(rq as isize, -(rq + rs) as isize)
+        } else {
+            Self::new(rq as isize, rr as isize)
+        }
+    }
+
+    /// Converts the hex to the offset coordinates of the given layout.
+    pub fn to_offset(self, layout: OffsetLayout) -> Offset {
+        let Self { q, r, .. } = self;
+        match layout {
+            OffsetLayout::OddR => Offset::new(q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenR => Offset::new(q + (r + (r & 1)) / 2, r),
+            OffsetLayout::OddQ => Offset::new(q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenQ => Offset::new(q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    /// Converts offset coordinates of the given layout to a hex.
+    pub fn from_offset(offset: Offset, layout: OffsetLayout) -> Self {
+        let Offset { col, row } = offset;
+        match layout {
+            OffsetLayout::OddR => Self::new(col - (row - (row & 1)) / 2, row),
+            OffsetLayout::EvenR => Self::new(col - (row + (row & 1)) / 2, row),
+            OffsetLayout::OddQ => Self::new(col, row - (col - (col & 1)) / 2),
+            OffsetLayout::EvenQ => Self::new(col, row - (col + (col & 1)) / 2),
+        }
+    }
+}
+
+impl Add for Cube {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Cube {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Cube {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for Cube {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Cube {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ORIGIN - self
+    }
+}
+
+/// Scales every coordinate.
+impl Mul<isize> for Cube {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Sum for Cube {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ORIGIN, Add::add)
+    }
+}
+
+/// Shows the coordinates like `(1, -2, 1)`.
+impl Display for Cube {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s)
+    }
+}
+
+/// A hex in axial coordinates, the `q` and `r` of cube coordinates.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Axial {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl From<Cube> for Axial {
+    fn from(cube: Cube) -> Self {
+        Self {
+            q: cube.q,
+            r: cube.r,
+        }
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(axial: Axial) -> Self {
+        Self::new(axial.q, axial.r)
+    }
+}
+
+/// Which rows or columns of an offset grid are shoved by half a hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetLayout {
+    /// Pointy topped, odd rows are shoved right.
+    OddR,
+    /// Pointy topped, even rows are shoved right.
+    EvenR,
+    /// Flat topped, odd columns are shoved down.
+    OddQ,
+    /// Flat topped, even columns are shoved down.
+    EvenQ,
+}
+
+/// A hex in offset coordinates, the column and row of a rectangular grid in
+/// some [OffsetLayout].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset {
+    pub col: isize,
+    pub row: isize,
+}
+
+impl Offset {
+    pub fn new(col: isize, row: isize) -> Self {
+        Self { col, row }
+    }
+}
+
+/// The directions to the neighbours of a hex on a pointy topped grid, which
+/// has rows of hexes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pointy {
+    E,
+    NE,
+    NW,
+    W,
+    SW,
+    SE,
+}
+
+/// The directions to the neighbours of a hex on a flat topped grid, which has
+/// columns of hexes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Flat {
+    SE,
+    NE,
+    N,
+    NW,
+    SW,
+    S,
+}
+
+macro_rules! directions {
+    ($name:ident { $($dir:ident = $text:literal),* }) => {
+        impl $name {
+            /// Every direction, counter clockwise.
+            pub const ALL: [Self; 6] = [$(Self::$dir),*];
+
+            /// Returns the offset of the neighbour in this direction.
+            pub fn offset(self) -> Cube {
+                DIRECTIONS[self as usize]
+            }
+        }
+
+        /// Parses the lowercase name of a direction, like `ne`.
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                match s {
+                    $($text => Ok(Self::$dir),)*
+                    _ => Err(Error::parse(format!("unknown direction `{}`", s))),
+                }
+            }
+        }
+    };
+}
+
+directions!(Pointy { E = "e", NE = "ne", NW = "nw", W = "w", SW = "sw", SE = "se" });
+directions!(Flat { SE = "se", NE = "ne", N = "n", NW = "nw", SW = "sw", S = "s" });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_work() {
+        assert_eq!(Pointy::E.offset(), Cube::new(1, 0));
+        assert_eq!(Pointy::SW.offset(), Cube::new(-1, 1));
+        assert_eq!(Flat::N.offset(), Cube::new(0, -1));
+        assert_eq!(Flat::SE.offset(), Pointy::E.offset());
+        assert_eq!("nw".parse::<Pointy>().unwrap(), Pointy::NW);
+        assert!("n".parse::<Pointy>().is_err());
+        assert_eq!("s".parse::<Flat>().unwrap(), Flat::S);
+
+        let sum: Cube = Pointy::ALL.iter().map(|dir| dir.offset()).sum();
+        assert_eq!(sum, Cube::ORIGIN);
+        assert!(Flat::ALL
+            .iter()
+            .all(|dir| dir.offset().distance(Cube::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn conversions_work() {
+        let hex = Cube::new(-1, -1);
+        assert_eq!(hex.s(), 2);
+        assert_eq!(Axial::from(hex), Axial { q: -1, r: -1 });
+        assert_eq!(hex.to_offset(OffsetLayout::OddR), Offset::new(-2, -1));
+        assert_eq!(hex.to_offset(OffsetLayout::EvenR), Offset::new(-1, -1));
+        assert_eq!(hex.to_offset(OffsetLayout::OddQ), Offset::new(-1, -2));
+        assert_eq!(hex.to_offset(OffsetLayout::EvenQ), Offset::new(-1, -1));
+
+        use OffsetLayout::*;
+        for layout in [OddR, EvenR, OddQ, EvenQ] {
+            for hex in Cube::ORIGIN.spiral(3) {
+                assert_eq!(Cube::from(Axial::from(hex)), hex);
+                assert_eq!(Cube::from_offset(hex.to_offset(layout), layout), hex);
+            }
+        }
+    }
+
+    #[test]
+    fn rings_and_spirals_work() {
+        let center = Cube::new(2, -5);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), [center]);
+        for radius in 1..5 {
+            let ring: Vec<_> = center.ring(radius).collect();
+            assert_eq!(ring.len(), 6 * radius);
+            assert!(ring.iter().all(|hex| hex.distance(center) == radius));
+        }
+        assert_eq!(center.neighbours().count(), 6);
+        assert_eq!(center.spiral(3).count(), 1 + 6 + 12 + 18);
+    }
+
+    #[test]
+    fn rotations_work() {
+        assert_eq!(Pointy::E.offset().rotate_right(), Pointy::SE.offset());
+        assert_eq!(Pointy::E.offset().rotate_left(), Pointy::NE.offset());
+
+        let hex = Cube::new(3, -1);
+        let turned = (0..6).fold(hex, |hex, _| hex.rotate_right());
+        assert_eq!(turned, hex);
+        assert_eq!(hex.rotate_right().distance(Cube::ORIGIN), 3);
+    }
+
+    #[test]
+    fn lines_work() {
+        let (from, to) = (Cube::new(-2, 1), Cube::new(3, -3));
+        let line: Vec<_> = from.line_to(to).collect();
+        assert_eq!(line.len(), from.distance(to) + 1);
+        assert_eq!((line[0], line[line.len() - 1]), (from, to));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+        assert_eq!(from.line_to(from).collect::<Vec<_>>(), [from]);
+
+        // A line along a direction is straight
+        let straight: Vec<_> = Cube::ORIGIN.line_to(Pointy::W.offset() * 3).collect();
+        assert_eq!(straight[2], Cube::new(-2, 0));
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{BenchReport, Error, Part, Result};
+
+const HEADER: &str = "commit,date,year,day,part,median_ns";
+
+/// The median duration of parsing or a part of a day in a benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// The commit which was benchmarked.
+    pub commit: String,
+    /// When the benchmark ran, see [utc_date](utc_date).
+    pub date: String,
+    pub year: u16,
+    pub day: u8,
+    /// The part which was benchmarked, or `None` for parsing.
+    pub part: Option<Part>,
+    pub median: Duration,
+}
+
+impl Record {
+    /// Returns the records of parsing and both parts of a benchmarked day.
+    pub fn from_report(
+        commit: &str,
+        date: &str,
+        year: u16,
+        day: u8,
+        report: &BenchReport,
+    ) -> Vec<Self> {
+        [
+            (None, &report.parse),
+            (Some(Part::One), &report.part1),
+            (Some(Part::Two), &report.part2),
+        ]
+        .iter()
+        .map(|&(part, stats)| Self {
+            commit: commit.to_string(),
+            date: date.to_string(),
+            year,
+            day,
+            part,
+            median: stats.median,
+        })
+        .collect()
+    }
+}
+
+/// The benchmark records of every run in the order they ran, which are kept
+/// in a CSV file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History(Vec<Record>);
+
+/// The year, day and part of a record, see [Record::part](Record::part).
+type Key = (u16, u8, Option<Part>);
+
+impl History {
+    /// Reads the history at the given path. A missing file is an empty
+    /// history.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Appends the records to the history at the given path, creating it with
+    /// a header if it does not exist yet.
+    pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)?;
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "{}", HEADER)?;
+        }
+
+        let mut s = String::new();
+        for record in records {
+            let part = match record.part {
+                None => "parse",
+                Some(Part::One) => "1",
+                Some(Part::Two) => "2",
+            };
+            s += &format!(
+                "{},{},{},{},{},{}\n",
+                record.commit,
+                record.date,
+                record.year,
+                record.day,
+                part,
+                record.median.as_nanos()
+            );
+        }
+        file.write_all(s.as_bytes())
+    }
+
+    /// Parses the contents of a history file.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut records = Vec::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let invalid = |msg: &str| Error::parse(format!("line {}: {}", i + 1, msg));
+            if line.is_empty() || line == HEADER {
+                continue;
+            }
+
+            let fields: Vec<_> = line.split(',').collect();
+            let [commit, date, year, day, part, median] = fields[..] else {
+                return Err(invalid("expected 6 fields"));
+            };
+            records.push(Record {
+                commit: commit.to_string(),
+                date: date.to_string(),
+                year: year.parse().map_err(|_| invalid("invalid year"))?,
+                day: day.parse().map_err(|_| invalid("invalid day"))?,
+                part: match part {
+                    "parse" => None,
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    _ => return Err(invalid("expected `parse`, `1` or `2`")),
+                },
+                median: Duration::from_nanos(
+                    median.parse().map_err(|_| invalid("invalid median"))?,
+                ),
+            });
+        }
+
+        Ok(Self(records))
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.0
+    }
+
+    /// Returns the commit of the last run.
+    pub fn last_commit(&self) -> Option<&str> {
+        self.0.last().map(|record| record.commit.as_str())
+    }
+
+    /// Returns the latest median of every day and part benchmarked on commits
+    /// whose hash starts with the hash of the given commit, or `None` if there
+    /// are none. Runs with uncommitted changes only match a `-dirty` commit.
+    fn run(&self, commit: &str) -> Option<BTreeMap<Key, Duration>> {
+        let (hash, dirty) = split_dirty(commit);
+        let run: BTreeMap<_, _> = self
+            .0
+            .iter()
+            .filter(|record| {
+                let (record_hash, record_dirty) = split_dirty(&record.commit);
+                record_dirty == dirty && record_hash.starts_with(hash)
+            })
+            .map(|record| ((record.year, record.day, record.part), record.median))
+            .collect();
+
+        Some(run).filter(|run| !run.is_empty())
+    }
+
+    /// Compares the latest medians of every day and part which was benchmarked
+    /// on both commits, matching every commit whose hash starts with the given
+    /// ones. Runs with uncommitted changes are only matched by a commit with a
+    /// `-dirty` suffix. Fails if a commit has no records.
+    pub fn compare(&self, baseline: &str, current: &str) -> io::Result<Vec<Change>> {
+        let run = |commit| {
+            self.run(commit).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no benchmarks of `{}`", commit),
+                )
+            })
+        };
+        let (baseline, current) = (run(baseline)?, run(current)?);
+
+        Ok(current
+            .into_iter()
+            .filter_map(|(key, after)| {
+                baseline.get(&key).map(|&before| Change {
+                    year: key.0,
+                    day: key.1,
+                    part: key.2,
+                    before,
+                    after,
+                })
+            })
+            .collect())
+    }
+}
+
+/// Splits a commit into its hash and whether it had uncommitted changes.
+fn split_dirty(commit: &str) -> (&str, bool) {
+    match commit.strip_suffix("-dirty") {
+        Some(hash) => (hash, true),
+        None => (commit, false),
+    }
+}
+
+/// The change of the median duration of parsing or a part between two runs,
+/// see [History::compare](History::compare).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<Part>,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Change {
+    /// Returns how much slower, or faster when negative, the part got in
+    /// percent.
+    pub fn percent(&self) -> f64 {
+        let before = self.before.as_secs_f64().max(f64::MIN_POSITIVE);
+        (self.after.as_secs_f64() / before - 1.0) * 100.0
+    }
+
+    /// Returns whether the part got more than `threshold` percent slower.
+    pub fn slower_than(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self.part {
+            Some(part) => part.to_string(),
+            None => "Parse".to_string(),
+        };
+        write!(
+            f,
+            "{} Day {:02} {:<5}  {:>10.3?} -> {:>10.3?}  {:>+7.1}%",
+            self.year,
+            self.day,
+            part,
+            self.before,
+            self.after,
+            self.percent()
+        )
+    }
+}
+
+/// Formats the time as a UTC date like `2020-12-24T06:00:00Z`.
+pub fn utc_date(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+
+    // Converts the days since 1970-01-01 to a date of the proleptic Gregorian
+    // calendar, with years starting in March so leap days come last
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: u8, part: Option<Part>, millis: u64) -> Record {
+        Record {
+            commit: commit.into(),
+            date: "2020-12-24T06:00:00Z".into(),
+            year: 2020,
+            day,
+            part,
+            median: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn history_roundtrips() {
+        let path = std::env::temp_dir().join("aoc-lib-history-roundtrips.csv");
+        let _ = fs::remove_file(&path);
+        let records = [
+            record("abc1234", 24, None, 1),
+            record("abc1234", 24, Some(Part::Two), 474),
+        ];
+
+        History::append(&path, &records[..1]).unwrap();
+        History::append(&path, &records[1..]).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().starts_with(HEADER));
+        assert_eq!(History::load(&path).unwrap().records(), &records[..]);
+
+        assert!(History::parse("abc1234,2020-12-24,2020,24,3,1").is_err());
+        assert!(History::parse("abc1234,2020,24,1,1").is_err());
+    }
+
+    #[test]
+    fn compare_works() {
+        let history = History(vec![
+            record("abc1234", 24, Some(Part::One), 10),
+            record("abc1234", 24, Some(Part::Two), 474),
+            record("def5678", 24, Some(Part::Two), 1000),
+            record("def5678", 24, Some(Part::Two), 948),
+            record("def5678", 25, Some(Part::One), 1),
+        ]);
+        assert_eq!(history.last_commit(), Some("def5678"));
+
+        let changes = history.compare("abc", "def5678").unwrap();
+        assert_eq!(changes.len(), 1);
+        let change = changes[0];
+        assert_eq!(
+            (change.day, change.part, change.after),
+            (24, Some(Part::Two), Duration::from_millis(948))
+        );
+        assert!((change.percent() - 100.0).abs() < 1e-9);
+        assert!(change.slower_than(10.0));
+        assert!(!change.slower_than(100.0));
+        assert_eq!(
+            change.to_string(),
+            "2020 Day 24 Part2   474.000ms ->  948.000ms   +100.0%"
+        );
+
+        assert!(history.compare("0000000", "def5678").is_err());
+    }
+
+    #[test]
+    fn compare_keeps_dirty_runs_apart() {
+        let history = History(vec![
+            record("abc1234", 24, Some(Part::Two), 474),
+            record("abc1234-dirty", 24, Some(Part::Two), 948),
+        ]);
+
+        let changes = history.compare("abc1234", "abc1234-dirty").unwrap();
+        assert_eq!(
+            (changes[0].before, changes[0].after),
+            (Duration::from_millis(474), Duration::from_millis(948))
+        );
+        let changes = history.compare("abc", "abc-dirty").unwrap();
+        assert_eq!(changes[0].before, Duration::from_millis(474));
+        assert!(History(vec![record("abc1234-dirty", 24, None, 1)])
+            .compare("abc1234", "abc1234-dirty")
+            .is_err());
+    }
+
+    #[test]
+    fn utc_date_works() {
+        assert_eq!(utc_date(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            utc_date(UNIX_EPOCH + Duration::from_secs(1_608_789_600)),
+            "2020-12-24T06:00:00Z"
+        );
+        assert_eq!(
+            utc_date(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+    }
+}
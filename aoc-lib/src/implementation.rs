@@ -0,0 +1,242 @@
+use std::{
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use crate::{Error, Params, Part, Result, Solver, TryPart1, TryPart2};
+
+/// Solves a part of a puzzle and returns the displayed answer.
+type SolveFn = dyn Fn(&str) -> Result<String> + Send + Sync;
+
+/// Returns a copy of an implementation with the given parameters overridden.
+type ConfigureFn = dyn Fn(&[(String, String)]) -> Result<Implementation> + Send + Sync;
+
+/// A named implementation of a single part, like a naive and an optimized
+/// approach of the same part, see
+/// [Registry::register_alternative](crate::Registry::register_alternative).
+#[derive(Clone)]
+pub struct Implementation {
+    pub name: &'static str,
+    pub part: Part,
+    solve: Arc<SolveFn>,
+    configure: Option<Arc<ConfigureFn>>,
+}
+
+impl Implementation {
+    /// The name of the implementation of a registered solution.
+    pub const DEFAULT: &'static str = "default";
+
+    /// Creates an implementation of part 1.
+    pub fn part1<S>(name: &'static str, solution: S) -> Self
+    where
+        S: TryPart1 + Send + Sync + 'static,
+        S::A: Display,
+    {
+        Self::new(name, Part::One, move |input| {
+            solution.try_solve(input).map(|ans| ans.to_string())
+        })
+    }
+
+    /// Creates an implementation of part 2.
+    pub fn part2<S>(name: &'static str, solution: S) -> Self
+    where
+        S: TryPart2 + Send + Sync + 'static,
+        S::B: Display,
+    {
+        Self::new(name, Part::Two, move |input| {
+            solution.try_solve(input).map(|ans| ans.to_string())
+        })
+    }
+
+    /// Creates an implementation of part 1 whose [Params] are overridden
+    /// together with those of the registered solution, see
+    /// [Entry::with_params](crate::Entry::with_params).
+    pub fn part1_with_params<S>(name: &'static str, solution: S) -> Self
+    where
+        S: TryPart1 + Params + Clone + Send + Sync + 'static,
+        S::A: Display,
+    {
+        Self {
+            configure: Some(Arc::new(configure(solution.clone(), move |solution| {
+                Self::part1_with_params(name, solution)
+            }))),
+            ..Self::part1(name, solution)
+        }
+    }
+
+    /// Creates an implementation of part 2 whose [Params] are overridden
+    /// together with those of the registered solution.
+    pub fn part2_with_params<S>(name: &'static str, solution: S) -> Self
+    where
+        S: TryPart2 + Params + Clone + Send + Sync + 'static,
+        S::B: Display,
+    {
+        Self {
+            configure: Some(Arc::new(configure(solution.clone(), move |solution| {
+                Self::part2_with_params(name, solution)
+            }))),
+            ..Self::part2(name, solution)
+        }
+    }
+
+    pub(crate) fn new<F>(name: &'static str, part: Part, solve: F) -> Self
+    where
+        F: Fn(&str) -> Result<String> + Send + Sync + 'static,
+    {
+        Self {
+            name,
+            part,
+            solve: Arc::new(solve),
+            configure: None,
+        }
+    }
+
+    /// Returns a copy of this implementation with the given `(name, value)`
+    /// parameters overridden. Fails if it was not created with parameters, a
+    /// parameter is unknown or a value is invalid.
+    pub fn with_params(&self, params: &[(String, String)]) -> Result<Self> {
+        match &self.configure {
+            _ if params.is_empty() => Ok(self.clone()),
+            Some(configure) => configure(params),
+            None => Err(Error::param(format!(
+                "the `{}` implementation of {} has no parameters",
+                self.name, self.part
+            ))),
+        }
+    }
+
+    /// Annotates the errors of this implementation with the day and its part.
+    pub(crate) fn at(self, day: u8) -> Self {
+        let Self {
+            name,
+            part,
+            solve,
+            configure,
+        } = self;
+        Self {
+            name,
+            part,
+            solve: Arc::new(move |input| solve(input).map_err(|err| err.at(day, part))),
+            configure: configure.map(|configure| -> Arc<ConfigureFn> {
+                Arc::new(move |params| configure(params).map(|alt| alt.at(day)))
+            }),
+        }
+    }
+}
+
+/// Returns a function which overrides the parameters of a copy of the solution
+/// and creates an implementation of it.
+fn configure<S: Params + Clone>(
+    solution: S,
+    create: impl Fn(S) -> Implementation,
+) -> impl Fn(&[(String, String)]) -> Result<Implementation> {
+    move |params| {
+        let mut solution = solution.clone();
+        for (name, value) in params {
+            solution.set_param(name, value)?;
+        }
+        Ok(create(solution))
+    }
+}
+
+impl Solver for Implementation {
+    type Answer = String;
+
+    fn solve(&self, input: &str) -> Result<Self::Answer> {
+        (self.solve)(input)
+    }
+}
+
+/// The answers of every implementation of a part on the same input, see
+/// [Entry::cross_check](crate::Entry::cross_check).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossCheck {
+    pub part: Part,
+    pub answers: Vec<(&'static str, Result<String>)>,
+}
+
+impl CrossCheck {
+    /// Returns whether every implementation gave the same answer.
+    pub fn agrees(&self) -> bool {
+        match self.answers.split_first() {
+            Some(((_, Ok(first)), rest)) => rest.iter().all(|(_, ans)| ans.as_ref() == Ok(first)),
+            Some(((_, Err(_)), _)) => false,
+            None => true,
+        }
+    }
+}
+
+impl Display for CrossCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.agrees() {
+            let names: Vec<_> = self.answers.iter().map(|(name, _)| *name).collect();
+            let answer = self.answers.first().and_then(|(_, ans)| ans.as_ref().ok());
+            return write!(
+                f,
+                "agree  {} ({})",
+                answer.map_or("", String::as_str),
+                names.join(", ")
+            );
+        }
+
+        write!(f, "DIFFER")?;
+        for (name, answer) in &self.answers {
+            match answer {
+                Ok(answer) => write!(f, "  {} = {}", name, answer)?,
+                Err(err) => write!(f, "  {} failed: {}", name, err)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Part1};
+
+    struct Len;
+
+    impl Part1 for Len {
+        type A = usize;
+
+        fn solve(&self, input: &str) -> Self::A {
+            input.len()
+        }
+    }
+
+    #[test]
+    fn implementation_solves_part() {
+        let implementation = Implementation::part1("len", Len);
+        assert_eq!(
+            (implementation.name, implementation.part),
+            ("len", Part::One)
+        );
+        assert_eq!(implementation.solve("abc"), Ok("3".into()));
+    }
+
+    #[test]
+    fn cross_check_works() {
+        let check = |answers| CrossCheck {
+            part: Part::One,
+            answers,
+        };
+
+        let agreed = check(vec![("default", Ok("3".into())), ("len", Ok("3".into()))]);
+        assert!(agreed.agrees());
+        assert_eq!(agreed.to_string(), "agree  3 (default, len)");
+
+        let differed = check(vec![
+            ("default", Ok("3".into())),
+            ("len", Ok("4".into())),
+            ("slow", Err(Error::no_answer("never"))),
+        ]);
+        assert!(!differed.agrees());
+        assert_eq!(
+            differed.to_string(),
+            "DIFFER  default = 3  len = 4  slow failed: no answer: never"
+        );
+
+        assert!(!check(vec![("default", Err(Error::no_answer("never")))]).agrees());
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    borrow::Cow,
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where the puzzle input of a solution is read from at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The input embedded in the binary at compile time.
+    #[default]
+    Embedded,
+    /// A single input file.
+    File(PathBuf),
+    /// A directory with an input file per day named like `09.txt`.
+    Dir(PathBuf),
+    /// The standard input of the process.
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a command line argument into an input source. A `-` means the
+    /// standard input, a directory means an input file per day and anything
+    /// else is the path to a single input file.
+    pub fn from_arg(arg: &str) -> Self {
+        let path = PathBuf::from(arg);
+        if arg == "-" {
+            InputSource::Stdin
+        } else if path.is_dir() {
+            InputSource::Dir(path)
+        } else {
+            InputSource::File(path)
+        }
+    }
+
+    /// Returns the source given by the first program argument, or the embedded
+    /// input if there is none.
+    pub fn from_env() -> Self {
+        env::args()
+            .nth(1)
+            .map(|arg| Self::from_arg(&arg))
+            .unwrap_or_default()
+    }
+
+    /// Reads the input for the given day. The embedded input is used as a
+    /// fallback when no other source was chosen.
+    pub fn read(&self, day: u8, embedded: &'static str) -> io::Result<Cow<'static, str>> {
+        match self {
+            InputSource::Embedded => Ok(Cow::Borrowed(embedded)),
+            InputSource::File(path) => fs::read_to_string(path).map(Cow::Owned),
+            InputSource::Dir(dir) => {
+                fs::read_to_string(dir.join(format!("{:02}.txt", day))).map(Cow::Owned)
+            }
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Owned(input))
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Embedded => write!(f, "embedded input"),
+            InputSource::File(path) | InputSource::Dir(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_arg_works() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(env!("CARGO_MANIFEST_DIR")),
+            InputSource::Dir(env!("CARGO_MANIFEST_DIR").into())
+        );
+        assert_eq!(
+            InputSource::from_arg("inputs/01.txt"),
+            InputSource::File("inputs/01.txt".into())
+        );
+    }
+
+    #[test]
+    fn read_works() {
+        assert_eq!(InputSource::Embedded.read(1, "(())").unwrap(), "(())");
+
+        let dir = env::temp_dir().join("aoc-lib-input-read-works");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("07.txt"), "123 -> x").unwrap();
+
+        assert_eq!(
+            InputSource::Dir(dir.clone()).read(7, "").unwrap(),
+            "123 -> x"
+        );
+        assert_eq!(
+            InputSource::File(dir.join("07.txt")).read(7, "").unwrap(),
+            "123 -> x"
+        );
+        assert!(InputSource::Dir(dir).read(8, "").is_err());
+    }
+}
@@ -1,9 +1,52 @@
 //! Advent of Code related concepts reside in this module.
 
+mod alloc;
+mod answers;
+mod automaton;
+mod bench;
+mod cancel;
+mod client;
+mod error;
+mod grid;
+pub mod hex;
+mod history;
+mod implementation;
+mod input;
+mod parallel;
+mod params;
 mod parts;
+mod point;
+mod registry;
+mod report;
+mod scan;
 mod solution;
 mod solver;
+mod submit;
+mod summary;
+mod testing;
 
-pub use parts::{Part1, Part2};
+pub use alloc::{AllocStats, CountingAlloc};
+pub use answers::{Answers, Verdict};
+pub use automaton::{
+    moore, von_neumann, Automaton, Backend, Dense, LifeLike, Rule, Sparse, Topology,
+};
+pub use bench::{BenchConfig, BenchReport, Stats};
+pub use cancel::{cancelled, check_cancelled, watch, CancelToken};
+pub use client::{Client, Config};
+pub use error::{Error, ErrorKind, Result};
+pub use grid::{Grid, NEIGHBOURS4, NEIGHBOURS8};
+pub use history::{utc_date, Change, History, Record};
+pub use implementation::{CrossCheck, Implementation};
+pub use input::InputSource;
+pub use parallel::{default_jobs, par_map};
+pub use params::{param_value, parse_param, Param, Params};
+pub use parts::{Part, Part1, Part2, TryPart1, TryPart2};
+pub use point::{Neighbours, Point};
+pub use registry::{solve_print, AnySolution, Entry, ParsedInput, PartSolver, Registry};
+pub use report::{write_csv, write_json, SolveReport, Status};
+pub use scan::{Captures, Pos, Scan, Scanner};
 pub use solution::Solution;
 pub use solver::Solver;
+pub use submit::{Guesses, Hint, Outcome, Rejection};
+pub use summary::Summary;
+pub use testing::check_answer;
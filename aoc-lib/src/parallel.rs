@@ -0,0 +1,86 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// The amount of threads to use when none was given, which is the available
+/// parallelism of the machine.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Applies `f` to every item on a pool of `jobs` threads and returns the
+/// results in the order of the items.
+///
+/// Every thread takes the next item which has not been taken yet, so slow
+/// items do not hold up the remaining items.
+pub fn par_map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let item = match items.get(index) {
+                    Some(item) => item,
+                    None => break,
+                };
+
+                let result = f(item);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is mapped"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread::ThreadId, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn par_map_keeps_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let squares = par_map(&items, 4, |&x| {
+            // Early items finish last
+            thread::sleep(Duration::from_millis(20 - x));
+            x * x
+        });
+
+        assert_eq!(squares, items.iter().map(|x| x * x).collect::<Vec<_>>());
+        assert!(par_map(&[] as &[u8], 4, |&x| x).is_empty());
+    }
+
+    #[test]
+    fn par_map_uses_threads() {
+        let items = [(); 8];
+        let ids: Vec<ThreadId> = par_map(&items, 4, |_| {
+            thread::sleep(Duration::from_millis(10));
+            thread::current().id()
+        });
+
+        let mut unique = ids.clone();
+        unique.sort_by_key(|id| format!("{:?}", id));
+        unique.dedup();
+        assert!(unique.len() > 1);
+        assert!(!ids.contains(&thread::current().id()));
+    }
+}
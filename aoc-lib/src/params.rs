@@ -0,0 +1,170 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{Error, Result};
+
+/// A named parameter of a solution with its current value, which is its
+/// default until it was overridden.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub value: String,
+    /// What the parameter changes, taken from its doc comment.
+    pub help: &'static str,
+}
+
+/// A solution with named, typed parameters which can be overridden before it
+/// runs, like the amount of rounds of a simulation. Usually implemented with
+/// [params](crate::params).
+pub trait Params {
+    /// Returns every parameter with its current value.
+    fn params(&self) -> Vec<Param>;
+
+    /// Overrides a parameter with a value parsed from the given text.
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()>;
+}
+
+/// Parses a `name=value` argument into the name and value of a parameter.
+pub fn parse_param(arg: &str) -> Result<(String, String)> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(Error::param(format!(
+            "expected `name=value`, got `{}`",
+            arg
+        ))),
+    }
+}
+
+/// Parses the value of a parameter, see [params](crate::params).
+pub fn param_value<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|err| Error::param(format!("`{}` for `{}`: {}", value, name, err)))
+}
+
+/// Implements [Params](crate::Params) for the given fields of a solution,
+/// which are its parameters. The doc comment of a field describes the
+/// parameter and the field type must implement `FromStr` and `Display`.
+///
+/// ```
+/// use aoc_lib::{Params, Part1, Part2};
+///
+/// struct Rounds {
+///     rounds: usize,
+/// }
+///
+/// aoc_lib::params! {
+///     Rounds {
+///         /// Times the input is repeated.
+///         rounds: usize,
+///     }
+/// }
+///
+/// let mut solution = Rounds { rounds: 2 };
+/// assert_eq!(solution.params()[0].value, "2");
+///
+/// solution.set_param("rounds", "5").unwrap();
+/// assert_eq!(solution.rounds, 5);
+/// assert!(solution.set_param("rounds", "many").is_err());
+/// assert!(solution.set_param("moves", "5").is_err());
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $solution:ty {
+            $($(#[doc = $doc:literal])* $field:ident: $type:ty),+ $(,)?
+        }
+    ) => {
+        impl $crate::Params for $solution {
+            fn params(&self) -> Vec<$crate::Param> {
+                vec![$($crate::Param {
+                    name: stringify!($field),
+                    value: self.$field.to_string(),
+                    help: concat!($($doc,)* "").trim(),
+                }),+]
+            }
+
+            fn set_param(&mut self, name: &str, value: &str) -> $crate::Result<()> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = $crate::param_value::<$type>(name, value)?;
+                    })+
+                    _ => {
+                        let known: &[&str] = &[$(stringify!($field)),+];
+                        return Err($crate::Error::param(format!(
+                            "unknown parameter `{}`, expected one of `{}`",
+                            name,
+                            known.join("`, `")
+                        )));
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    struct Crab {
+        moves: usize,
+        label: String,
+    }
+
+    crate::params! {
+        Crab {
+            /// Moves of the crab.
+            moves: usize,
+            label: String,
+        }
+    }
+
+    #[test]
+    fn params_works() {
+        let mut crab = Crab {
+            moves: 100,
+            label: "cups".into(),
+        };
+        assert_eq!(
+            crab.params(),
+            vec![
+                Param {
+                    name: "moves",
+                    value: "100".into(),
+                    help: "Moves of the crab.",
+                },
+                Param {
+                    name: "label",
+                    value: "cups".into(),
+                    help: "",
+                },
+            ]
+        );
+
+        crab.set_param("moves", "10").unwrap();
+        crab.set_param("label", "x=y").unwrap();
+        assert_eq!((crab.moves, crab.label.as_str()), (10, "x=y"));
+
+        let err = crab.set_param("moves", "-1").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Param);
+        let err = crab.set_param("cups", "9").unwrap_err();
+        assert_eq!(
+            err.message,
+            "unknown parameter `cups`, expected one of `moves`, `label`"
+        );
+    }
+
+    #[test]
+    fn parse_param_works() {
+        assert_eq!(parse_param("moves=10"), Ok(("moves".into(), "10".into())));
+        assert_eq!(parse_param("label=a=b"), Ok(("label".into(), "a=b".into())));
+        assert!(parse_param("moves").is_err());
+        assert!(parse_param("=10").is_err());
+    }
+}
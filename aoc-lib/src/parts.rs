@@ -1,4 +1,27 @@
-use std::fmt::Display;
+use std::fmt::{self, Display};
+
+use crate::Result;
+
+/// Identifies one of the two parts of an Advent of Code puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts in the order they are solved.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part1"),
+            Part::Two => write!(f, "Part2"),
+        }
+    }
+}
 
 /// A solver for part 1 of an Advent of Code puzzle.
 pub trait Part1 {
@@ -24,3 +47,38 @@ pub trait Part2 {
 
     fn solve(&self, input: &str) -> Self::B;
 }
+
+/// A fallible solver for part 1 of an Advent of Code puzzle.
+///
+/// Unlike [Part1](Part1) the implementer may not assume the input is valid and
+/// returns an [Error](crate::Error) for malformed input instead of panicking.
+/// Every [Part1](Part1) is also a TryPart1 which never fails.
+pub trait TryPart1 {
+    type A: Display;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A>;
+}
+
+impl<T: Part1> TryPart1 for T {
+    type A = T::A;
+
+    fn try_solve(&self, input: &str) -> Result<Self::A> {
+        Ok(Part1::solve(self, input))
+    }
+}
+
+/// A fallible solver for part 2 of an Advent of Code puzzle, see
+/// [TryPart1](TryPart1) for more info.
+pub trait TryPart2 {
+    type B: Display;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B>;
+}
+
+impl<T: Part2> TryPart2 for T {
+    type B = T::B;
+
+    fn try_solve(&self, input: &str) -> Result<Self::B> {
+        Ok(Part2::solve(self, input))
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
+
+/// A point with `N` integer coordinates, like `Point<3>` for `(x, y, z)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<const N: usize>(pub [isize; N]);
+
+impl<const N: usize> Point<N> {
+    /// The point with every coordinate 0.
+    pub const ORIGIN: Self = Self([0; N]);
+
+    pub fn new(coords: [isize; N]) -> Self {
+        Self(coords)
+    }
+
+    /// Returns the sum of the absolute differences of the coordinates, the
+    /// amount of orthogonal steps between the points.
+    pub fn manhattan(self, other: Self) -> usize {
+        self.diffs(other).sum()
+    }
+
+    /// Returns the largest absolute difference of the coordinates, the amount
+    /// of steps between the points when diagonal steps are allowed too.
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.diffs(other).max().unwrap_or_default()
+    }
+
+    fn diffs(self, other: Self) -> impl Iterator<Item = usize> {
+        (0..N).map(move |i| self.0[i].abs_diff(other.0[i]))
+    }
+
+    /// Returns the `3^N - 1` points which differ by at most 1 in every
+    /// coordinate, which share a side, edge or corner with this point.
+    pub fn neighbours(self) -> Neighbours<N> {
+        Neighbours {
+            center: self,
+            index: 0,
+        }
+    }
+
+    /// Returns the `2 * N` points which differ by 1 in a single coordinate,
+    /// which share a side with this point.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |i| {
+            [-1, 1].iter().map(move |&delta| {
+                let mut neighbour = self;
+                neighbour.0[i] += delta;
+                neighbour
+            })
+        })
+    }
+
+    /// Returns the offsets of the [neighbours](Point::neighbours) of a point.
+    pub fn neighbour_offsets() -> Neighbours<N> {
+        Self::ORIGIN.neighbours()
+    }
+}
+
+/// An iterator over the neighbours of a point, see [Point::neighbours].
+#[derive(Debug, Clone)]
+pub struct Neighbours<const N: usize> {
+    center: Point<N>,
+    /// The offset of the next neighbour, whose base 3 digits are the
+    /// coordinates of the offset plus 1.
+    index: usize,
+}
+
+impl<const N: usize> Neighbours<N> {
+    /// The amount of offsets including the center, `3^N`.
+    const OFFSETS: usize = 3usize.pow(N as u32);
+}
+
+impl<const N: usize> Iterator for Neighbours<N> {
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The middle index is the center itself
+        if self.index == Self::OFFSETS / 2 {
+            self.index += 1;
+        }
+        if self.index >= Self::OFFSETS {
+            return None;
+        }
+
+        let mut point = self.center;
+        let mut digits = self.index;
+        for coord in &mut point.0 {
+            *coord += (digits % 3) as isize - 1;
+            digits /= 3;
+        }
+        self.index += 1;
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let center = usize::from(self.index <= Self::OFFSETS / 2);
+        let len = Self::OFFSETS.saturating_sub(self.index + center);
+        (len, Some(len))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Neighbours<N> {}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::ORIGIN
+    }
+}
+
+impl<const N: usize> From<[isize; N]> for Point<N> {
+    fn from(coords: [isize; N]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (coord, rhs) in self.0.iter_mut().zip(rhs.0) {
+            *coord += rhs;
+        }
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (coord, rhs) in self.0.iter_mut().zip(rhs.0) {
+            *coord -= rhs;
+        }
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ORIGIN - self
+    }
+}
+
+/// Scales every coordinate.
+impl<const N: usize> Mul<isize> for Point<N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: isize) -> Self::Output {
+        for coord in &mut self.0 {
+            *coord *= rhs;
+        }
+        self
+    }
+}
+
+/// Shows the coordinates like `(1, -2, 3)`.
+impl<const N: usize> Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, coord) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", coord)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn arithmetic_works() {
+        let (a, b) = (Point([1, -2, 3]), Point([4, 5, -6]));
+        assert_eq!(a + b, Point([5, 3, -3]));
+        assert_eq!(a - b, Point([-3, -7, 9]));
+        assert_eq!(-a, Point([-1, 2, -3]));
+        assert_eq!(a * 2, Point([2, -4, 6]));
+        assert_eq!(a[2], 3);
+        assert_eq!(Point::<2>::default(), Point::ORIGIN);
+        assert_eq!(a.to_string(), "(1, -2, 3)");
+    }
+
+    #[test]
+    fn distances_work() {
+        let (a, b) = (Point([1, -2, 3]), Point([4, 5, -6]));
+        assert_eq!(a.manhattan(b), 3 + 7 + 9);
+        assert_eq!(a.chebyshev(b), 9);
+        assert_eq!(Point([]).chebyshev(Point([])), 0);
+    }
+
+    #[test]
+    fn neighbours_work() {
+        let center = Point([10, 20]);
+        let neighbours: Vec<_> = center.neighbours().collect();
+        assert_eq!(neighbours.len(), 8);
+        assert_eq!(center.neighbours().len(), 8);
+        assert!(!neighbours.contains(&center));
+        assert!(neighbours.iter().all(|&n| n.chebyshev(center) == 1));
+
+        let offsets = Point::<1>::neighbour_offsets().collect::<HashSet<_>>();
+        assert_eq!(offsets.len(), 2);
+        let offsets = Point::<3>::neighbour_offsets().collect::<HashSet<_>>();
+        assert_eq!(offsets.len(), 26);
+        let offsets = Point::<4>::neighbour_offsets().collect::<HashSet<_>>();
+        assert_eq!(offsets.len(), 80);
+
+        let orthogonal: Vec<_> = center.orthogonal_neighbours().collect();
+        assert_eq!(
+            orthogonal,
+            [
+                Point([9, 20]),
+                Point([11, 20]),
+                Point([10, 19]),
+                Point([10, 21])
+            ]
+        );
+    }
+}
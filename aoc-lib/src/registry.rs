@@ -0,0 +1,832 @@
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    process,
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    bench,
+    solver::{measured, phase_header, timed},
+    watch, AllocStats, Answers, BenchConfig, BenchReport, CrossCheck, Error, Implementation,
+    InputSource, Param, Params, Part, Result, Solution, SolveReport, Solver, Status,
+};
+
+/// An object safe [Solution](Solution) with its parsed input and answers
+/// erased.
+///
+/// This allows solutions with different parsed input and answer types to live
+/// in the same [Registry](Registry). Every [Solution](Solution) which can be
+/// shared between threads implements this trait.
+pub trait AnySolution: Send + Sync {
+    /// Parses the input into the [Parsed](Solution::Parsed) type of the
+    /// solution.
+    fn parse(&self, input: &str) -> Result<ParsedInput>;
+
+    /// Solves the given part of the puzzle and returns the displayed answer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input was parsed by a different solution.
+    fn solve(&self, part: Part, parsed: &ParsedInput) -> Result<String>;
+}
+
+impl<T> AnySolution for T
+where
+    T: Solution + Send + Sync,
+    T::Parsed: Send + Sync + 'static,
+{
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        Solution::parse(self, input).map(|parsed| ParsedInput(Arc::new(parsed)))
+    }
+
+    fn solve(&self, part: Part, parsed: &ParsedInput) -> Result<String> {
+        let parsed = parsed
+            .0
+            .downcast_ref()
+            .expect("input was parsed by a different solution");
+
+        match part {
+            Part::One => self.part1(parsed).map(|ans| ans.to_string()),
+            Part::Two => self.part2(parsed).map(|ans| ans.to_string()),
+        }
+    }
+}
+
+/// The input of an [AnySolution](AnySolution) in its parsed shape, which is
+/// cheap to clone.
+#[derive(Clone)]
+pub struct ParsedInput(Arc<dyn Any + Send + Sync>);
+
+/// A solution with [Params](Params) whose parameters can be overridden after
+/// its type was erased.
+trait Configure: Send + Sync {
+    fn params(&self) -> Vec<Param>;
+
+    /// Returns a copy of the solution with the given parameters overridden,
+    /// both as a solution and as a configurable solution.
+    fn configure(
+        &self,
+        params: &[(String, String)],
+    ) -> Result<(Arc<dyn AnySolution>, Arc<dyn Configure>)>;
+}
+
+impl<T> Configure for T
+where
+    T: AnySolution + Params + Clone + 'static,
+{
+    fn params(&self) -> Vec<Param> {
+        Params::params(self)
+    }
+
+    fn configure(
+        &self,
+        params: &[(String, String)],
+    ) -> Result<(Arc<dyn AnySolution>, Arc<dyn Configure>)> {
+        let mut solution = self.clone();
+        for (name, value) in params {
+            solution.set_param(name, value)?;
+        }
+
+        let solution = Arc::new(solution);
+        Ok((solution.clone(), solution))
+    }
+}
+
+/// A [Solver](Solver) for a single part of a registered solution which parses
+/// the input before solving the part. Errors are annotated with the day and
+/// part.
+pub struct PartSolver<'a> {
+    entry: &'a Entry,
+    part: Part,
+}
+
+impl Solver for PartSolver<'_> {
+    type Answer = String;
+
+    fn solve(&self, input: &str) -> Result<Self::Answer> {
+        let parsed = self.entry.parse(input)?;
+        self.entry.solve(self.part, &parsed)
+    }
+}
+
+/// A solution registered for a specific year and day, which is cheap to clone.
+#[derive(Clone)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// The puzzle input embedded in the binary, see
+    /// [Entry::read_input](Entry::read_input) for reading other inputs.
+    pub input: &'static str,
+    solution: Arc<dyn AnySolution>,
+    configure: Option<Arc<dyn Configure>>,
+    alternatives: Vec<Implementation>,
+}
+
+impl Entry {
+    /// Returns a solver for the given part of this entry.
+    pub fn part(&self, part: Part) -> PartSolver<'_> {
+        PartSolver { entry: self, part }
+    }
+
+    /// Returns every implementation of the given part, starting with the
+    /// registered solution named [Implementation::DEFAULT] followed by its
+    /// alternatives, see [Registry::register_alternative].
+    pub fn implementations(&self, part: Part) -> Vec<Implementation> {
+        let entry = self.clone();
+        let default = Implementation::new(Implementation::DEFAULT, part, move |input| {
+            entry.part(part).solve(input)
+        });
+
+        let alternatives = self.alternatives.iter().filter(|alt| alt.part == part);
+        std::iter::once(default)
+            .chain(alternatives.cloned())
+            .collect()
+    }
+
+    /// Returns whether any part has more than one implementation.
+    pub fn has_alternatives(&self) -> bool {
+        !self.alternatives.is_empty()
+    }
+
+    /// Solves the given part of the input with every implementation to check
+    /// whether they agree on the answer.
+    pub fn cross_check(&self, part: Part, input: &str) -> CrossCheck {
+        let answers = self
+            .implementations(part)
+            .into_iter()
+            .map(|implementation| (implementation.name, implementation.solve(input)))
+            .collect();
+        CrossCheck { part, answers }
+    }
+
+    /// Returns the parameters of the solution with their current values, see
+    /// [Registry::register_with_params](Registry::register_with_params).
+    pub fn params(&self) -> Vec<Param> {
+        self.configure
+            .as_ref()
+            .map(|configure| configure.params())
+            .unwrap_or_default()
+    }
+
+    /// Returns a copy of this entry with the given `(name, value)` parameters
+    /// overridden in the solution and its alternatives. Fails if the solution
+    /// or an alternative has no parameter with one of the names or a value is
+    /// invalid.
+    pub fn with_params(&self, params: &[(String, String)]) -> Result<Entry> {
+        if params.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let configure = self.configure.as_ref().ok_or_else(|| {
+            Error::param(format!(
+                "{} day {:02} has no parameters",
+                self.year, self.day
+            ))
+        })?;
+        let (solution, configure) = configure
+            .configure(params)
+            .map_err(|err| err.at_day(self.day))?;
+        let alternatives = self
+            .alternatives
+            .iter()
+            .map(|alternative| alternative.with_params(params))
+            .collect::<Result<_>>()
+            .map_err(|err| err.at_day(self.day))?;
+
+        Ok(Entry {
+            solution,
+            configure: Some(configure),
+            alternatives,
+            ..self.clone()
+        })
+    }
+
+    /// Reads the puzzle input of this entry from the given source.
+    pub fn read_input(&self, source: &InputSource) -> io::Result<Cow<'static, str>> {
+        source.read(self.day, self.input)
+    }
+
+    /// Parses the input once for both parts. Errors are annotated with the
+    /// day.
+    pub fn parse(&self, input: &str) -> Result<ParsedInput> {
+        self.solution
+            .parse(input)
+            .map_err(|err| err.at_day(self.day))
+    }
+
+    /// Solves the given part with input parsed by [Entry::parse](Entry::parse).
+    /// Errors are annotated with the day and part.
+    pub fn solve(&self, part: Part, parsed: &ParsedInput) -> Result<String> {
+        self.solution
+            .solve(part, parsed)
+            .map_err(|err| err.at(self.day, part))
+    }
+
+    /// Parses the input once, then solves and times both parts of this entry.
+    /// Both parts report the parse error if the input could not be parsed.
+    pub fn reports(&self, input: &str) -> Vec<SolveReport> {
+        self.reports_within(input, None)
+    }
+
+    /// Like [Entry::reports](Entry::reports), but parsing and each part run
+    /// under a [watchdog](watch) with the given time limit if there is one.
+    pub fn reports_within(&self, input: &str, limit: Option<Duration>) -> Vec<SolveReport> {
+        let (parsed, parse, _) = {
+            let (entry, input) = (self.clone(), input.to_string());
+            phase(limit, move || entry.parse(&input))
+        };
+
+        Part::ALL
+            .iter()
+            .map(|&part| {
+                let (ans, duration, allocs) = match &parsed {
+                    Ok(parsed) => {
+                        let (entry, parsed) = (self.clone(), parsed.clone());
+                        phase(limit, move || entry.solve(part, &parsed))
+                    }
+                    Err(err) => (Err(err.clone()), Default::default(), None),
+                };
+                let (answer, status) = match ans {
+                    Ok(ans) => (ans, Status::Solved),
+                    Err(err) => (err.message, Status::from(err.kind)),
+                };
+
+                SolveReport {
+                    year: self.year,
+                    day: self.day,
+                    part,
+                    answer,
+                    parse,
+                    duration,
+                    allocs,
+                    status,
+                }
+            })
+            .collect()
+    }
+
+    /// Runs and prints the answer of both parts, see
+    /// [Solution::solve_print](Solution::solve_print).
+    pub fn solve_print(&self, input: &str) -> Result<()> {
+        let (parsed, elapsed, allocs) = measured(|| self.parse(input));
+        println!("{}", phase_header("Parse", elapsed, allocs));
+        let parsed = parsed?;
+
+        for part in Part::ALL.iter().copied() {
+            let (ans, elapsed, allocs) = measured(|| self.solve(part, &parsed));
+            println!("{}\n{}", phase_header(part, elapsed, allocs), ans?);
+        }
+        Ok(())
+    }
+
+    /// Benchmarks parsing and both parts on input parsed once beforehand, see
+    /// [Solver::bench](Solver::bench).
+    pub fn bench(&self, input: &str, config: &BenchConfig) -> Result<BenchReport> {
+        let parse = bench::bench_with(|| timed(|| self.parse(input).map(drop)), config)?;
+        let parsed = self.parse(input)?;
+        let part =
+            |part| bench::bench_with(|| timed(|| self.solve(part, &parsed).map(drop)), config);
+
+        Ok(BenchReport {
+            parse,
+            part1: part(Part::One)?,
+            part2: part(Part::Two)?,
+        })
+    }
+}
+
+/// Times and counts the allocations of `f`, on a watched thread if there is a
+/// time limit. A phase which timed out took the whole limit and its
+/// allocations are unknown.
+fn phase<T, F>(limit: Option<Duration>, f: F) -> (Result<T>, Duration, Option<AllocStats>)
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    match limit {
+        Some(limit) => {
+            watch(limit, move || Ok(measured(f))).unwrap_or_else(|err| (Err(err), limit, None))
+        }
+        None => measured(f),
+    }
+}
+
+/// A collection of every known solution ordered by year and day.
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+    dirs: BTreeMap<u16, PathBuf>,
+}
+
+impl Registry {
+    /// Registers a solution for the given year and day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a solution was already registered for the year and day.
+    pub fn register<S: AnySolution + 'static>(
+        &mut self,
+        year: u16,
+        day: u8,
+        input: &'static str,
+        solution: S,
+    ) {
+        self.insert(Entry {
+            year,
+            day,
+            input,
+            solution: Arc::new(solution),
+            configure: None,
+            alternatives: Vec::new(),
+        });
+    }
+
+    /// Registers a solution with [Params](Params) for the given year and day,
+    /// whose parameters can be overridden with
+    /// [Entry::with_params](Entry::with_params).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a solution was already registered for the year and day.
+    pub fn register_with_params<S: AnySolution + Params + Clone + 'static>(
+        &mut self,
+        year: u16,
+        day: u8,
+        input: &'static str,
+        solution: S,
+    ) {
+        let solution = Arc::new(solution);
+        self.insert(Entry {
+            year,
+            day,
+            input,
+            solution: solution.clone(),
+            configure: Some(solution),
+            alternatives: Vec::new(),
+        });
+    }
+
+    /// Registers another implementation of a part of the solution registered
+    /// for the given year and day, like a naive approach next to an optimized
+    /// one. Errors of the implementation are annotated with the day and part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no solution was registered for the year and day, or if the
+    /// part already has an implementation with the same name.
+    pub fn register_alternative(&mut self, year: u16, day: u8, implementation: Implementation) {
+        let entry = match self
+            .entries
+            .binary_search_by_key(&(year, day), |e| (e.year, e.day))
+        {
+            Ok(index) => &mut self.entries[index],
+            Err(_) => panic!("{} day {:02} is not registered", year, day),
+        };
+
+        let (name, part) = (implementation.name, implementation.part);
+        if entry
+            .implementations(part)
+            .iter()
+            .any(|known| known.name == name)
+        {
+            panic!(
+                "{} day {:02} {} already has an implementation named `{}`",
+                year, day, part, name
+            );
+        }
+
+        entry.alternatives.push(implementation.at(day));
+    }
+
+    fn insert(&mut self, entry: Entry) {
+        match self
+            .entries
+            .binary_search_by_key(&(entry.year, entry.day), |e| (e.year, e.day))
+        {
+            Ok(_) => panic!("{} day {:02} is already registered", entry.year, entry.day),
+            Err(index) => self.entries.insert(index, entry),
+        }
+    }
+
+    /// Sets the directory of a year which contains its `inputs/` directory and
+    /// `answers.toml` file.
+    pub fn set_dir(&mut self, year: u16, dir: impl Into<PathBuf>) {
+        self.dirs.insert(year, dir.into());
+    }
+
+    /// Returns the directory of a year, see [Registry::set_dir](Registry::set_dir).
+    pub fn dir(&self, year: u16) -> Option<&Path> {
+        self.dirs.get(&year).map(PathBuf::as_path)
+    }
+
+    /// Loads the known answers of a year from the `answers.toml` file in its
+    /// directory. A year without a directory or file has no answers.
+    pub fn answers(&self, year: u16) -> io::Result<Answers> {
+        match self.dir(year) {
+            Some(dir) => Answers::load(&dir.join("answers.toml")),
+            None => Ok(Answers::default()),
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.entries
+            .binary_search_by_key(&(year, day), |e| (e.year, e.day))
+            .ok()
+            .map(|index| &self.entries[index])
+    }
+
+    /// Returns every registered entry of the given year.
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| entry.year == year)
+    }
+
+    /// Returns every year which has at least one registered entry.
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<_> = self.entries.iter().map(|entry| entry.year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+}
+
+/// Runs and prints the answers of a single day of a year whose solutions are
+/// registered by `register`. The input is read from the source given as the
+/// first program argument and defaults to the embedded input, see
+/// [InputSource::from_env](InputSource::from_env).
+///
+/// Exits the process with an error message if the input could not be read or
+/// solved.
+///
+/// # Panics
+///
+/// Panics if there is no solution for the given day.
+pub fn solve_print(register: fn(&mut Registry), year: u16, day: u8) {
+    let mut registry = Registry::default();
+    register(&mut registry);
+
+    let entry = registry
+        .get(year, day)
+        .unwrap_or_else(|| panic!("day {:02} of {} is not registered", day, year));
+    let result = entry
+        .read_input(&InputSource::from_env())
+        .map_err(|err| err.to_string())
+        .and_then(|input| entry.solve_print(&input).map_err(|err| err.to_string()));
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Part1, Part2, TryPart1, TryPart2};
+
+    struct Echo;
+
+    impl Part1 for Echo {
+        type A = usize;
+
+        fn solve(&self, input: &str) -> Self::A {
+            input.len()
+        }
+    }
+
+    impl Part2 for Echo {
+        type B = String;
+
+        fn solve(&self, input: &str) -> Self::B {
+            input.to_uppercase()
+        }
+    }
+
+    #[test]
+    fn registry_is_ordered() {
+        let mut registry = Registry::default();
+        registry.register(2020, 2, "", Echo);
+        registry.register(2015, 1, "", Echo);
+        registry.register(2020, 1, "", Echo);
+
+        let keys: Vec<_> = registry.iter().map(|e| (e.year, e.day)).collect();
+        assert_eq!(keys, vec![(2015, 1), (2020, 1), (2020, 2)]);
+        assert_eq!(registry.years(), vec![2015, 2020]);
+        assert_eq!(registry.year(2020).count(), 2);
+    }
+
+    #[test]
+    fn entry_solves_erased_parts() {
+        let mut registry = Registry::default();
+        registry.register(2020, 1, "abc", Echo);
+
+        let entry = registry.get(2020, 1).unwrap();
+        assert_eq!(entry.part(Part::One).solve(entry.input), Ok("3".into()));
+        assert_eq!(entry.part(Part::Two).solve(entry.input), Ok("ABC".into()));
+        assert!(registry.get(2020, 2).is_none());
+    }
+
+    struct Picky;
+
+    impl TryPart1 for Picky {
+        type A = u8;
+
+        fn try_solve(&self, input: &str) -> Result<Self::A> {
+            Ok(input.parse()?)
+        }
+    }
+
+    impl TryPart2 for Picky {
+        type B = u8;
+
+        fn try_solve(&self, _: &str) -> Result<Self::B> {
+            Err(Error::no_answer("never"))
+        }
+    }
+
+    #[test]
+    fn entry_annotates_errors() {
+        let mut registry = Registry::default();
+        registry.register(2020, 9, "x", Picky);
+
+        let entry = registry.get(2020, 9).unwrap();
+        assert_eq!(entry.part(Part::One).solve("42"), Ok("42".into()));
+
+        let err = entry.part(Part::One).solve(entry.input).unwrap_err();
+        assert_eq!((err.day, err.part), (Some(9), Some(Part::One)));
+
+        let err = entry.solve_print("42").unwrap_err();
+        assert_eq!((err.day, err.part), (Some(9), Some(Part::Two)));
+    }
+
+    #[test]
+    fn entry_reports_parts() {
+        let mut registry = Registry::default();
+        registry.register(2020, 9, "x", Picky);
+        let entry = registry.get(2020, 9).unwrap();
+
+        let reports = entry.reports("42");
+        let report = &reports[0];
+        assert_eq!((report.year, report.day, report.part), (2020, 9, Part::One));
+        assert_eq!(
+            (report.answer.as_str(), report.status),
+            ("42", Status::Solved)
+        );
+
+        let report = &reports[1];
+        assert_eq!(
+            (report.answer.as_str(), report.status),
+            ("never", Status::NoAnswer)
+        );
+
+        let reports = entry.reports(entry.input);
+        assert_eq!(reports[0].status, Status::InvalidInput);
+    }
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Parsed = Vec<u32>;
+        type A = u32;
+        type B = u32;
+
+        fn parse(&self, input: &str) -> Result<Self::Parsed> {
+            input.split(',').map(|n| Ok(n.parse()?)).collect()
+        }
+
+        fn part1(&self, parsed: &Self::Parsed) -> Result<Self::A> {
+            Ok(parsed.iter().sum())
+        }
+
+        fn part2(&self, parsed: &Self::Parsed) -> Result<Self::B> {
+            parsed
+                .iter()
+                .copied()
+                .max()
+                .ok_or_else(|| Error::no_answer("no numbers"))
+        }
+    }
+
+    #[test]
+    fn entry_shares_parsed_input() {
+        let mut registry = Registry::default();
+        registry.register(2020, 1, "", Numbers);
+        let entry = registry.get(2020, 1).unwrap();
+
+        let parsed = entry.parse("1,5,3").unwrap();
+        assert_eq!(entry.solve(Part::One, &parsed), Ok("9".into()));
+        assert_eq!(entry.solve(Part::Two, &parsed), Ok("5".into()));
+        assert_eq!(entry.part(Part::Two).solve("2,4"), Ok("4".into()));
+
+        let err = entry.parse("1,x").err().unwrap();
+        assert_eq!((err.day, err.part), (Some(1), None));
+
+        let reports = entry.reports("1,x");
+        assert!(reports
+            .iter()
+            .all(|report| report.status == Status::InvalidInput));
+    }
+
+    struct Endless;
+
+    impl TryPart1 for Endless {
+        type A = u8;
+
+        fn try_solve(&self, _: &str) -> Result<Self::A> {
+            Ok(1)
+        }
+    }
+
+    impl TryPart2 for Endless {
+        type B = u8;
+
+        fn try_solve(&self, _: &str) -> Result<Self::B> {
+            loop {
+                crate::check_cancelled()?;
+            }
+        }
+    }
+
+    #[test]
+    fn entry_reports_timeouts() {
+        let mut registry = Registry::default();
+        registry.register(2015, 4, "", Endless);
+        let entry = registry.get(2015, 4).unwrap();
+
+        let limit = Duration::from_millis(20);
+        let reports = entry.reports_within("", Some(limit));
+        assert_eq!(reports[0].status, Status::Solved);
+        assert!(reports[0].duration < limit);
+        assert_eq!(reports[1].status, Status::Timeout);
+        assert_eq!(reports[1].duration, limit);
+    }
+
+    #[derive(Clone)]
+    struct Repeat {
+        times: usize,
+    }
+
+    crate::params! {
+        Repeat {
+            /// Times the input is repeated.
+            times: usize,
+        }
+    }
+
+    impl Part1 for Repeat {
+        type A = String;
+
+        fn solve(&self, input: &str) -> Self::A {
+            input.repeat(self.times)
+        }
+    }
+
+    impl Part2 for Repeat {
+        type B = usize;
+
+        fn solve(&self, input: &str) -> Self::B {
+            input.len() * self.times
+        }
+    }
+
+    #[test]
+    fn entry_overrides_params() {
+        let mut registry = Registry::default();
+        registry.register_with_params(2020, 23, "ab", Repeat { times: 2 });
+        registry.register(2020, 24, "", Echo);
+
+        let entry = registry.get(2020, 23).unwrap();
+        assert_eq!(entry.params()[0].value, "2");
+
+        let configured = entry.with_params(&[("times".into(), "3".into())]).unwrap();
+        assert_eq!(configured.params()[0].value, "3");
+        assert_eq!(
+            configured.part(Part::One).solve(entry.input),
+            Ok("ababab".into())
+        );
+        assert_eq!(entry.part(Part::Two).solve(entry.input), Ok("4".into()));
+
+        let err = entry
+            .with_params(&[("times".into(), "x".into())])
+            .err()
+            .unwrap();
+        assert_eq!((err.kind, err.day), (crate::ErrorKind::Param, Some(23)));
+
+        let echo = registry.get(2020, 24).unwrap();
+        assert!(echo.params().is_empty());
+        assert!(echo.with_params(&[]).is_ok());
+        assert!(echo.with_params(&[("times".into(), "3".into())]).is_err());
+    }
+
+    struct Shout;
+
+    impl Part2 for Shout {
+        type B = String;
+
+        fn solve(&self, input: &str) -> Self::B {
+            input.to_ascii_uppercase()
+        }
+    }
+
+    #[test]
+    fn entry_cross_checks_alternatives() {
+        let mut registry = Registry::default();
+        registry.register(2020, 1, "abc", Echo);
+        registry.register_alternative(2020, 1, Implementation::part2("ascii", Shout));
+        registry.register_alternative(2020, 1, Implementation::part2("picky", Picky));
+
+        let entry = registry.get(2020, 1).unwrap();
+        assert!(entry.has_alternatives());
+        let names: Vec<_> = entry
+            .implementations(Part::Two)
+            .iter()
+            .map(|implementation| implementation.name)
+            .collect();
+        assert_eq!(names, vec!["default", "ascii", "picky"]);
+        assert_eq!(entry.implementations(Part::One).len(), 1);
+
+        assert!(entry.cross_check(Part::One, "abc").agrees());
+        let check = entry.cross_check(Part::Two, "abc");
+        assert!(!check.agrees());
+        assert_eq!(check.answers[1].1, Ok("ABC".into()));
+        let err = check.answers[2].1.clone().unwrap_err();
+        assert_eq!((err.day, err.part), (Some(1), Some(Part::Two)));
+    }
+
+    #[test]
+    fn entry_overrides_params_of_alternatives() {
+        let mut registry = Registry::default();
+        registry.register_with_params(2020, 23, "ab", Repeat { times: 2 });
+        registry.register_alternative(
+            2020,
+            23,
+            Implementation::part1_with_params("again", Repeat { times: 2 }),
+        );
+        registry.register_with_params(2020, 24, "ab", Repeat { times: 2 });
+        registry.register_alternative(
+            2020,
+            24,
+            Implementation::part1("fixed", Repeat { times: 2 }),
+        );
+
+        let params = [("times".to_string(), "3".to_string())];
+        let entry = registry
+            .get(2020, 23)
+            .unwrap()
+            .with_params(&params)
+            .unwrap();
+        let check = entry.cross_check(Part::One, entry.input);
+        assert!(check.agrees());
+        assert_eq!(check.answers[1].1, Ok("ababab".into()));
+
+        let err = registry
+            .get(2020, 24)
+            .unwrap()
+            .with_params(&params)
+            .err()
+            .unwrap();
+        assert_eq!((err.kind, err.day), (crate::ErrorKind::Param, Some(24)));
+        assert!(registry.get(2020, 24).unwrap().with_params(&[]).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn registry_rejects_duplicate_alternatives() {
+        let mut registry = Registry::default();
+        registry.register(2020, 1, "", Echo);
+        registry.register_alternative(2020, 1, Implementation::part2("ascii", Shout));
+        registry.register_alternative(2020, 1, Implementation::part2("ascii", Shout));
+    }
+
+    #[test]
+    fn registry_loads_answers() {
+        let dir = std::env::temp_dir().join("aoc-lib-registry-loads-answers");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("answers.toml"), "[09]\npart1 = 42\n").unwrap();
+
+        let mut registry = Registry::default();
+        registry.set_dir(2020, &dir);
+
+        assert_eq!(registry.dir(2020), Some(dir.as_path()));
+        assert_eq!(
+            registry.answers(2020).unwrap().get(9, Part::One),
+            Some("42")
+        );
+        assert_eq!(registry.answers(2015).unwrap(), Answers::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn registry_rejects_duplicates() {
+        let mut registry = Registry::default();
+        registry.register(2020, 1, "", Echo);
+        registry.register(2020, 1, "", Echo);
+    }
+}
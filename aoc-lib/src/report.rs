@@ -0,0 +1,212 @@
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    time::Duration,
+};
+
+use crate::{AllocStats, ErrorKind, Part};
+
+/// The outcome of solving a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    InvalidInput,
+    NoAnswer,
+    Timeout,
+}
+
+impl From<ErrorKind> for Status {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Parse | ErrorKind::Param => Status::InvalidInput,
+            ErrorKind::NoAnswer => Status::NoAnswer,
+            ErrorKind::Timeout => Status::Timeout,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::InvalidInput => write!(f, "invalid_input"),
+            Status::NoAnswer => write!(f, "no_answer"),
+            Status::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+/// The result of solving a single part of a registered solution, see
+/// [Entry::reports](crate::Entry::reports).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The displayed answer, or the error message if the part was not solved.
+    pub answer: String,
+    /// Time spent parsing the input, which is shared by both parts.
+    pub parse: Duration,
+    /// Time spent solving the part on the parsed input.
+    pub duration: Duration,
+    /// What solving the part allocated, if the
+    /// [CountingAlloc](crate::CountingAlloc) is installed.
+    pub allocs: Option<AllocStats>,
+    pub status: Status,
+}
+
+/// Writes the reports as a JSON array of objects. Durations are given in
+/// nanoseconds, allocations are `null` if they were not counted.
+pub fn write_json<W: Write>(mut w: W, reports: &[SolveReport]) -> io::Result<()> {
+    writeln!(w, "[")?;
+    for (i, report) in reports.iter().enumerate() {
+        writeln!(
+            w,
+            "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": \"{}\", \"parse_ns\": {}, \"duration_ns\": {}, \"allocs\": {}, \"alloc_bytes\": {}, \"peak_bytes\": {}, \"status\": \"{}\"}}{}",
+            report.year,
+            report.day,
+            part_number(report.part),
+            json_escape(&report.answer),
+            report.parse.as_nanos(),
+            report.duration.as_nanos(),
+            alloc_field(report, |a| a.allocs, "null"),
+            alloc_field(report, |a| a.bytes, "null"),
+            alloc_field(report, |a| a.peak, "null"),
+            report.status,
+            if i + 1 < reports.len() { "," } else { "" }
+        )?;
+    }
+    writeln!(w, "]")
+}
+
+/// Writes the reports as CSV with a header row. Durations are given in
+/// nanoseconds, allocations are empty if they were not counted.
+pub fn write_csv<W: Write>(mut w: W, reports: &[SolveReport]) -> io::Result<()> {
+    writeln!(
+        w,
+        "year,day,part,answer,parse_ns,duration_ns,allocs,alloc_bytes,peak_bytes,status"
+    )?;
+    for report in reports {
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{},{},{},{}",
+            report.year,
+            report.day,
+            part_number(report.part),
+            csv_escape(&report.answer),
+            report.parse.as_nanos(),
+            report.duration.as_nanos(),
+            alloc_field(report, |a| a.allocs, ""),
+            alloc_field(report, |a| a.bytes, ""),
+            alloc_field(report, |a| a.peak, ""),
+            report.status
+        )?;
+    }
+    Ok(())
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Formats a field of the allocations, or `none` if they were not counted.
+fn alloc_field(report: &SolveReport, field: impl Fn(&AllocStats) -> u64, none: &str) -> String {
+    report
+        .allocs
+        .as_ref()
+        .map_or_else(|| none.to_string(), |allocs| field(allocs).to_string())
+}
+
+fn json_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Quotes the field if it contains a separator, quote or line break.
+fn csv_escape(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<SolveReport> {
+        vec![
+            SolveReport {
+                year: 2020,
+                day: 20,
+                part: Part::One,
+                answer: "19955159604613".into(),
+                parse: Duration::from_micros(3),
+                duration: Duration::from_micros(17),
+                allocs: Some(AllocStats {
+                    allocs: 4,
+                    bytes: 1024,
+                    peak: 512,
+                }),
+                status: Status::Solved,
+            },
+            SolveReport {
+                year: 2020,
+                day: 20,
+                part: Part::Two,
+                answer: "invalid tile header `x, \"y\"`".into(),
+                parse: Duration::from_nanos(2),
+                duration: Duration::from_nanos(5),
+                allocs: None,
+                status: Status::InvalidInput,
+            },
+        ]
+    }
+
+    #[test]
+    fn write_json_works() {
+        let mut out = Vec::new();
+        write_json(&mut out, &reports()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[\n\
+            \x20 {\"year\": 2020, \"day\": 20, \"part\": 1, \"answer\": \"19955159604613\", \"parse_ns\": 3000, \"duration_ns\": 17000, \"allocs\": 4, \"alloc_bytes\": 1024, \"peak_bytes\": 512, \"status\": \"solved\"},\n\
+            \x20 {\"year\": 2020, \"day\": 20, \"part\": 2, \"answer\": \"invalid tile header `x, \\\"y\\\"`\", \"parse_ns\": 2, \"duration_ns\": 5, \"allocs\": null, \"alloc_bytes\": null, \"peak_bytes\": null, \"status\": \"invalid_input\"}\n\
+            ]\n"
+        );
+    }
+
+    #[test]
+    fn write_csv_works() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &reports()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "year,day,part,answer,parse_ns,duration_ns,allocs,alloc_bytes,peak_bytes,status\n\
+            2020,20,1,19955159604613,3000,17000,4,1024,512,solved\n\
+            2020,20,2,\"invalid tile header `x, \"\"y\"\"`\",2,5,,,,invalid_input\n"
+        );
+    }
+
+    #[test]
+    fn json_escape_works() {
+        assert_eq!(json_escape("a\"b\\c\nd\u{1}"), "a\\\"b\\\\c\\nd\\u0001");
+    }
+}
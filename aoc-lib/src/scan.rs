@@ -0,0 +1,520 @@
+use std::{
+    fmt::{self, Display},
+    iter,
+    str::FromStr,
+};
+
+use crate::{Error, Grid, Result};
+
+/// A position in the input, starting from line 1 and column 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// A cursor over part of the input, like a line or a block, which scans it
+/// piece by piece. Errors tell the position in the whole input where scanning
+/// went wrong.
+///
+/// ```
+/// use aoc_lib::{scan, Scanner};
+///
+/// let input = "London to Dublin = 464\nLondon to Belfast = 518";
+/// let mut lines = Scanner::new(input).lines();
+///
+/// let (from, to, dist): (&str, &str, usize) = scan!(lines.next().unwrap(), "{} to {} = {}")?;
+/// assert_eq!((from, to, dist), ("London", "Dublin", 464));
+///
+/// let err = scan!(lines.next().unwrap(), "{} to {} = {}").map(|(_, _, dist): (&str, &str, u8)| dist);
+/// assert_eq!(
+///     err.unwrap_err().message,
+///     "line 2, column 21: invalid integer `518`: number too large to fit in target type"
+/// );
+/// # Ok::<(), aoc_lib::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    source: &'a str,
+    offset: usize,
+    end: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            source: input,
+            offset: 0,
+            end: input.len(),
+        }
+    }
+
+    /// Returns what is left to scan.
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset == self.end
+    }
+
+    /// Returns the position of what is left to scan.
+    pub fn pos(&self) -> Pos {
+        let before = &self.source[..self.offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Pos {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Creates a parse error at the current position.
+    pub fn error(&self, message: impl Display) -> Error {
+        Error::parse(format!("{}: {}", self.pos(), message))
+    }
+
+    /// Describes what comes next for errors, like `` `abc` `` or `end of line`.
+    fn found(&self) -> String {
+        let next = if self.is_empty() {
+            &self.source[self.end..]
+        } else {
+            self.rest()
+        };
+        match next.chars().next() {
+            None => "end of input".to_owned(),
+            Some('\n') | Some('\r') => "end of line".to_owned(),
+            Some(c) if c.is_whitespace() => "whitespace".to_owned(),
+            Some(_) => {
+                let token: String = next.chars().take_while(|c| !c.is_whitespace()).collect();
+                format!("`{}`", token)
+            }
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let taken = &self.rest()[..len];
+        self.offset += len;
+        taken
+    }
+
+    /// Skips the literal if it comes next, returns whether it did.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.advance(literal.len());
+        }
+        found
+    }
+
+    /// Skips the literal, which has to come next.
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`, found {}", literal, self.found())))
+        }
+    }
+
+    /// Skips the first of the literals which comes next and returns its value.
+    pub fn one_of<T>(&mut self, choices: impl IntoIterator<Item = (&'static str, T)>) -> Result<T> {
+        let mut expected = Vec::new();
+        for (literal, value) in choices {
+            if self.eat(literal) {
+                return Ok(value);
+            }
+            expected.push(format!("`{}`", literal));
+        }
+        Err(self.error(format!(
+            "expected one of {}, found {}",
+            expected.join(", "),
+            self.found()
+        )))
+    }
+
+    /// Takes the characters as long as they match.
+    pub fn take_while(&mut self, mut f: impl FnMut(char) -> bool) -> &'a str {
+        let len = self
+            .rest()
+            .find(|c| !f(c))
+            .unwrap_or_else(|| self.rest().len());
+        self.advance(len)
+    }
+
+    /// Skips spaces and tabs, but not line breaks.
+    pub fn skip_spaces(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    /// Scans an integer with an optional sign, like `-12`.
+    pub fn integer<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let start = *self;
+        let sign = usize::from(self.rest().starts_with(['-', '+']));
+        let digits = self.rest()[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest().len() - sign);
+        if digits == 0 {
+            return Err(self.error(format!("expected an integer, found {}", self.found())));
+        }
+
+        let integer = self.advance(sign + digits);
+        integer
+            .parse()
+            .map_err(|err| start.error(format!("invalid integer `{}`: {}", integer, err)))
+    }
+
+    /// Scans letters, digits and underscores, like `abc_12`.
+    pub fn identifier(&mut self) -> Result<&'a str> {
+        let identifier = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        if identifier.is_empty() {
+            Err(self.error(format!("expected an identifier, found {}", self.found())))
+        } else {
+            Ok(identifier)
+        }
+    }
+
+    /// Scans one or more items with the separator between them.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Checks that nothing is left to scan.
+    pub fn end(&self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected {}", self.found())))
+        }
+    }
+
+    /// Takes the rest of the current line and skips the line break.
+    pub fn line(&mut self) -> Scanner<'a> {
+        let mut line = *self;
+        let len = self.rest().find('\n').unwrap_or_else(|| self.rest().len());
+        line.end = line.offset + self.rest()[..len].trim_end_matches('\r').len();
+        self.offset = (self.offset + len + 1).min(self.end);
+        line
+    }
+
+    /// Returns every line, like [str::lines].
+    pub fn lines(self) -> impl Iterator<Item = Scanner<'a>> {
+        let mut rest = self;
+        iter::from_fn(move || (!rest.is_empty()).then(|| rest.line()))
+    }
+
+    /// Returns the blocks of lines which are separated by blank lines.
+    pub fn blocks(self) -> impl Iterator<Item = Scanner<'a>> {
+        let blank = |line: &Scanner| line.rest().trim().is_empty();
+        let mut lines = self.lines().peekable();
+        iter::from_fn(move || {
+            while blank(lines.peek()?) {
+                lines.next();
+            }
+            let mut block = lines.next()?;
+            while let Some(line) = lines.next_if(|line| !blank(line)) {
+                block.end = line.end;
+            }
+            Some(block)
+        })
+    }
+
+    /// Scans the values of the `{}` placeholders in the pattern, the text
+    /// around them has to match. A placeholder takes the text on the same line
+    /// up to the text after it, or the rest of the line if it ends the pattern.
+    /// If that text is not on the line, the error points at where the longest
+    /// start of it is. See [scan!](crate::scan!) to scan everything.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of placeholders differs from the amount of values,
+    /// or if two placeholders are not separated by text.
+    pub fn scan<T: Captures<'a>>(&mut self, pattern: &str) -> Result<T> {
+        assert_eq!(
+            pattern.matches("{}").count(),
+            T::COUNT,
+            "`{}` does not have a placeholder for every value",
+            pattern
+        );
+
+        let mut literals = pattern.split("{}").peekable();
+        self.literal(literals.next().unwrap_or_default())?;
+
+        let mut captures = Vec::with_capacity(T::COUNT);
+        while let Some(literal) = literals.next() {
+            assert!(
+                !literal.is_empty() || literals.peek().is_none(),
+                "the placeholders of `{}` are not separated by text",
+                pattern
+            );
+
+            let mut capture = *self;
+            let line = self.rest().split('\n').next().unwrap_or_default();
+            let len = match line.find(literal) {
+                Some(len) if !literal.is_empty() => len,
+                None if !literal.is_empty() => {
+                    // Fail where the longest start of the literal is found, as
+                    // that is most likely where the line went wrong
+                    let line = line.trim_end_matches('\r');
+                    let len = literal
+                        .char_indices()
+                        .rev()
+                        .filter(|&(i, _)| i > 0)
+                        .map(|(i, _)| &literal[..i])
+                        .find_map(|start| line.find(start))
+                        .unwrap_or(line.len());
+                    self.advance(len);
+                    let found = match &line[len..] {
+                        "" => self.found(),
+                        rest => format!("`{}`", rest),
+                    };
+                    return Err(self.error(format!("expected `{}`, found {}", literal, found)));
+                }
+                _ => line.trim_end_matches('\r').len(),
+            };
+            self.advance(len);
+            capture.end = self.offset;
+            captures.push(capture);
+
+            self.literal(literal)?;
+        }
+
+        T::from_captures(&captures)
+    }
+
+    /// Scans the rest as a grid with a cell per character, see [Grid::parse].
+    pub fn grid<T>(&mut self, mut f: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut rows = *self;
+        rows.end = self.offset + self.rest().trim_end_matches(['\n', '\r']).len();
+
+        let mut cells = Vec::with_capacity(rows.rest().len());
+        let mut width = None;
+        for mut line in rows.lines() {
+            let start = line;
+            let row = cells.len();
+            while let Some(c) = line.rest().chars().next() {
+                let cell = f(c).ok_or_else(|| line.error(format!("unexpected `{}`", c)))?;
+                cells.push(cell);
+                line.advance(c.len_utf8());
+            }
+
+            let len = cells.len() - row;
+            match width {
+                Some(width) if width != len => {
+                    return Err(Error::parse(format!(
+                        "line {}: expected {} cells, found {}",
+                        start.pos().line,
+                        width,
+                        len
+                    )));
+                }
+                _ => width = Some(len),
+            }
+        }
+
+        self.offset = self.end;
+        Ok(Grid::from_vec(width.unwrap_or_default(), cells))
+    }
+}
+
+impl<'a> From<&'a str> for Scanner<'a> {
+    fn from(input: &'a str) -> Self {
+        Self::new(input)
+    }
+}
+
+/// A value which can be scanned from the text of a placeholder, see
+/// [Scanner::scan]. Scanning a [Scanner] gives the text with its position.
+pub trait Scan<'a>: Sized {
+    fn scan(capture: Scanner<'a>) -> Result<Self>;
+}
+
+impl<'a> Scan<'a> for Scanner<'a> {
+    fn scan(capture: Scanner<'a>) -> Result<Self> {
+        Ok(capture)
+    }
+}
+
+impl<'a> Scan<'a> for &'a str {
+    fn scan(capture: Scanner<'a>) -> Result<Self> {
+        Ok(capture.rest())
+    }
+}
+
+macro_rules! scan_from_str {
+    ($kind:literal: $($t:ty),+) => {
+        $(
+            impl Scan<'_> for $t {
+                fn scan(capture: Scanner<'_>) -> Result<Self> {
+                    let text = capture.rest();
+                    text.parse().map_err(|err| {
+                        capture.error(format!("invalid {} `{}`: {}", $kind, text, err))
+                    })
+                }
+            }
+        )+
+    };
+}
+
+scan_from_str!("text": String);
+scan_from_str!("character": char);
+scan_from_str!("boolean": bool);
+scan_from_str!("number": f32, f64);
+scan_from_str!("integer": u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The values of every placeholder of a pattern, a single [Scan] value or a
+/// tuple of them.
+pub trait Captures<'a>: Sized {
+    /// The amount of values.
+    const COUNT: usize;
+
+    fn from_captures(captures: &[Scanner<'a>]) -> Result<Self>;
+}
+
+impl<'a, T: Scan<'a>> Captures<'a> for T {
+    const COUNT: usize = 1;
+
+    fn from_captures(captures: &[Scanner<'a>]) -> Result<Self> {
+        T::scan(captures[0])
+    }
+}
+
+macro_rules! tuple_captures {
+    ($count:literal: $($t:ident $i:tt),+) => {
+        impl<'a, $($t: Scan<'a>),+> Captures<'a> for ($($t,)+) {
+            const COUNT: usize = $count;
+
+            fn from_captures(captures: &[Scanner<'a>]) -> Result<Self> {
+                Ok(($($t::scan(captures[$i])?,)+))
+            }
+        }
+    };
+}
+
+tuple_captures!(2: A 0, B 1);
+tuple_captures!(3: A 0, B 1, C 2);
+tuple_captures!(4: A 0, B 1, C 2, D 3);
+tuple_captures!(5: A 0, B 1, C 2, D 3, E 4);
+tuple_captures!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Scans the values of the `{}` placeholders of a pattern from a string or a
+/// [Scanner], which has to match the pattern completely. See [Scanner::scan].
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal) => {{
+        let mut scanner = $crate::Scanner::from($input);
+        scanner
+            .scan($pattern)
+            .and_then(|values| scanner.end().map(|_| values))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_work() {
+        let mut s = Scanner::new("move -12 to abc_3, x");
+        s.literal("move ").unwrap();
+        assert_eq!(s.integer::<i32>(), Ok(-12));
+        assert!(!s.eat("from"));
+        assert_eq!(s.one_of(vec![("from", 0), (" to ", 1)]), Ok(1));
+        assert_eq!(
+            s.separated(", ", Scanner::identifier),
+            Ok(vec!["abc_3", "x"])
+        );
+        assert_eq!(s.end(), Ok(()));
+
+        let mut s = Scanner::new("a\n 300");
+        s.line();
+        s.skip_spaces();
+        let err = s.integer::<u8>().unwrap_err();
+        assert_eq!(
+            err.message,
+            "line 2, column 2: invalid integer `300`: number too large to fit in target type"
+        );
+        assert_eq!(
+            s.identifier().unwrap_err().message,
+            "line 2, column 5: expected an identifier, found end of input"
+        );
+        assert_eq!(
+            Scanner::new("x").one_of(vec![("on", true), ("off", false)]),
+            Err(Error::parse(
+                "line 1, column 1: expected one of `on`, `off`, found `x`"
+            ))
+        );
+    }
+
+    #[test]
+    fn lines_and_blocks_work() {
+        let input = "\na\r\nb\n\n\nc\n";
+        let lines: Vec<_> = Scanner::new(input).lines().map(|l| l.rest()).collect();
+        assert_eq!(lines, input.lines().collect::<Vec<_>>());
+
+        let blocks: Vec<_> = Scanner::new(input).blocks().collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].rest(), "a\r\nb");
+        assert_eq!(blocks[1].rest(), "c");
+        assert_eq!(blocks[1].pos(), Pos { line: 6, column: 1 });
+    }
+
+    #[test]
+    fn patterns_work() {
+        let (name, x, y): (&str, i64, char) =
+            scan!("pos of ab c = <-3,z>", "pos of {} = <{},{}>").unwrap();
+        assert_eq!((name, x, y), ("ab c", -3, 'z'));
+
+        let line = Scanner::new("x\nTile 12:").lines().nth(1).unwrap();
+        let id: usize = scan!(line, "Tile {}:").unwrap();
+        assert_eq!(id, 12);
+
+        let (list, last): (Scanner, String) = scan!("1, 2 (last 3)", "{} (last {})").unwrap();
+        assert_eq!(list.pos().column, 1);
+        assert_eq!(last, "3");
+
+        let err = scan!("a to b", "{} to {} = {}").map(|(_, _, d): (&str, &str, u8)| d);
+        assert_eq!(
+            err.unwrap_err().message,
+            "line 1, column 7: expected ` = `, found end of input"
+        );
+        let err =
+            scan!("turn on 0,0 to 9,9", "turn on {} through {}").map(|(_, _): (&str, &str)| ());
+        assert_eq!(
+            err.unwrap_err().message,
+            "line 1, column 12: expected ` through `, found ` to 9,9`"
+        );
+        let err = scan!("1 to 2x", "{} to {}").map(|(a, b): (u8, u8)| a + b);
+        assert_eq!(
+            err.unwrap_err().message,
+            "line 1, column 6: invalid integer `2x`: invalid digit found in string"
+        );
+        let err = scan!("1 to 2 ", "{} to {} ").map(|(a, b): (u8, u8)| a + b);
+        assert!(err.is_ok());
+        assert!(scan!("1 to 2\n", "{} to {}")
+            .map(|(a, b): (u8, u8)| a + b)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "placeholder for every value")]
+    fn patterns_check_placeholders() {
+        let _: Result<(u8, u8)> = scan!("1", "{}");
+    }
+}
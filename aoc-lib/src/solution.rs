@@ -1,19 +1,68 @@
-use crate::{Part1, Part2, Solver};
+use std::fmt::Display;
+
+use crate::{
+    solver::{measured, phase_header},
+    Result, TryPart1, TryPart2,
+};
 
 /// A solver for both parts of an Advent of Code puzzle.
-pub trait Solution: Part1 + Part2 {
+///
+/// The input is parsed once into the [Parsed](Solution::Parsed) type which is
+/// shared by both parts, so parsing and solving can be timed separately.
+///
+/// Both infallible ([Part1](crate::Part1) + [Part2](crate::Part2)) and
+/// fallible ([TryPart1](TryPart1) + [TryPart2](TryPart2)) solvers are a
+/// solution which parse their input while solving each part.
+pub trait Solution {
+    /// The puzzle input in the shape both parts work on.
+    type Parsed;
+    /// The answer for part 1.
+    type A: Display;
+    /// The answer for part 2.
+    type B: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Self::A>;
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Self::B>;
+
     /// Runs and prints the answer for the given solution.
     ///
-    /// This procedure will display the runtime duration and answer for both parts.
-    /// Answers are displayed on their own newline after the part header text with
-    /// the runtime timings.
-    fn solve_print(&self, input: &str)
-    where
-        Self: Sized,
-    {
-        (self as &dyn Part1<A = Self::A>).solve_print(input, "Part1");
-        (self as &dyn Part2<B = Self::B>).solve_print(input, "Part2");
+    /// This procedure will display the runtime duration of parsing followed by
+    /// the runtime duration and answer for both parts. Answers are displayed
+    /// on their own newline after the part header text with the runtime
+    /// timings, and the allocations if the
+    /// [CountingAlloc](crate::CountingAlloc) is installed. Returns the error
+    /// of the first phase which failed.
+    fn solve_print(&self, input: &str) -> Result<()> {
+        let (parsed, elapsed, allocs) = measured(|| self.parse(input));
+        println!("{}", phase_header("Parse", elapsed, allocs));
+        let parsed = parsed?;
+
+        let (ans, elapsed, allocs) = measured(|| self.part1(&parsed));
+        println!("{}\n{}", phase_header("Part1", elapsed, allocs), ans?);
+        let (ans, elapsed, allocs) = measured(|| self.part2(&parsed));
+        println!("{}\n{}", phase_header("Part2", elapsed, allocs), ans?);
+        Ok(())
     }
 }
 
-impl<T: Part1 + Part2> Solution for T {}
+/// Solutions written as separate parts receive the raw input in both parts.
+impl<T: TryPart1 + TryPart2> Solution for T {
+    type Parsed = String;
+    type A = <T as TryPart1>::A;
+    type B = <T as TryPart2>::B;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Self::A> {
+        TryPart1::try_solve(self, parsed)
+    }
+
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Self::B> {
+        TryPart2::try_solve(self, parsed)
+    }
+}
@@ -3,38 +3,96 @@ use std::{
     time::{Duration, Instant},
 };
 
-use crate::{Part1, Part2};
+use crate::{
+    alloc, bench, AllocStats, BenchConfig, Part1, Part2, Result, Stats, TryPart1, TryPart2,
+};
 
 pub trait Solver {
     type Answer: Display;
 
-    fn solve(&self, input: &str) -> Self::Answer;
+    fn solve(&self, input: &str) -> Result<Self::Answer>;
+
+    /// Prints the header with the runtime duration followed by the answer on
+    /// its own line. Nothing is printed if the solver failed.
+    fn solve_print(&self, input: &str, header: &str) -> Result<()> {
+        let (ans, elapsed, allocs) = self.measure(input);
+        println!("{}\n{}", phase_header(header, elapsed, allocs), ans?);
+        Ok(())
+    }
+
+    fn time(&self, input: &str) -> (Result<Self::Answer>, Duration) {
+        timed(|| self.solve(input))
+    }
 
-    fn solve_print(&self, input: &str, header: &str) {
-        let (ans, elapsed) = self.time(input);
-        println!("{}({:?})\n{}", header, elapsed, ans)
+    /// Like [Solver::time](Solver::time), but also returns what the solver
+    /// allocated if the [CountingAlloc](crate::CountingAlloc) is installed.
+    fn measure(&self, input: &str) -> (Result<Self::Answer>, Duration, Option<AllocStats>) {
+        measured(|| self.solve(input))
     }
 
-    fn time(&self, input: &str) -> (Self::Answer, Duration) {
-        let now = Instant::now();
-        let ans = self.solve(input);
-        let elapsed = now.elapsed();
-        (ans, elapsed)
+    /// Repeatedly [times](Solver::time) the solver after warming up and
+    /// returns statistics of the measured runs. Fails on the first run which
+    /// fails.
+    fn bench(&self, input: &str, config: &BenchConfig) -> Result<Stats> {
+        bench::bench(self, input, config)
+    }
+}
+
+/// Runs `f` and returns its result together with how long it ran.
+pub(crate) fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let now = Instant::now();
+    let result = f();
+    (result, now.elapsed())
+}
+
+/// Runs `f` and returns its result together with how long it ran and what it
+/// allocated on the current thread.
+pub(crate) fn measured<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<AllocStats>) {
+    let ((result, elapsed), allocs) = alloc::measure(|| timed(f));
+    (result, elapsed, allocs)
+}
+
+/// Formats the header of a printed phase, e.g. `Part1(1.2ms)`, followed by
+/// the allocations if they were counted.
+pub(crate) fn phase_header(
+    name: impl Display,
+    elapsed: Duration,
+    allocs: Option<AllocStats>,
+) -> String {
+    match allocs {
+        Some(allocs) => format!("{}({:?}; {})", name, elapsed, allocs),
+        None => format!("{}({:?})", name, elapsed),
     }
 }
 
 impl<T: Display> Solver for &dyn Part1<A = T> {
     type Answer = T;
 
-    fn solve(&self, input: &str) -> Self::Answer {
-        Part1::solve(*self, input)
+    fn solve(&self, input: &str) -> Result<Self::Answer> {
+        Ok(Part1::solve(*self, input))
     }
 }
 
 impl<T: Display> Solver for &dyn Part2<B = T> {
     type Answer = T;
 
-    fn solve(&self, input: &str) -> Self::Answer {
-        Part2::solve(*self, input)
+    fn solve(&self, input: &str) -> Result<Self::Answer> {
+        Ok(Part2::solve(*self, input))
+    }
+}
+
+impl<T: Display> Solver for &dyn TryPart1<A = T> {
+    type Answer = T;
+
+    fn solve(&self, input: &str) -> Result<Self::Answer> {
+        TryPart1::try_solve(*self, input)
+    }
+}
+
+impl<T: Display> Solver for &dyn TryPart2<B = T> {
+    type Answer = T;
+
+    fn solve(&self, input: &str) -> Result<Self::Answer> {
+        TryPart2::try_solve(*self, input)
     }
 }
@@ -0,0 +1,520 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    answers::{parse_value, strip_comment},
+    utc_date, Error, Part, Result,
+};
+
+/// Whether a wrong answer was too high or too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The answer is wrong, with a hint if it is a number and the time to wait
+    /// before the next answer may be submitted.
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was checked.
+    Wait(Duration),
+    /// The part was solved before, nothing was checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Parses the page the website responds with, `None` if it is not a
+    /// response to an answer.
+    pub fn parse(html: &str) -> Option<Self> {
+        let text = html.to_lowercase();
+
+        if text.contains("that's the right answer") {
+            Some(Outcome::Correct)
+        } else if text.contains("that's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(Outcome::Wrong {
+                hint,
+                wait: parse_wait(&text),
+            })
+        } else if text.contains("you gave an answer too recently") {
+            Some(Outcome::Wait(
+                parse_wait(&text).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if text.contains("you don't seem to be solving the right level") {
+            Some(Outcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong { hint, wait } => {
+                write!(f, "wrong")?;
+                if let Some(hint) = hint {
+                    write!(f, ", {}", hint)?;
+                }
+                if let Some(wait) = wait {
+                    write!(f, ", wait {:?} before submitting again", wait)?;
+                }
+                Ok(())
+            }
+            Outcome::Wait(wait) => write!(f, "submitted too recently, wait {:?}", wait),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Finds how long to wait in texts like `you have 4m 32s left to wait` or
+/// `please wait one minute before trying again`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("you have ")?;
+        let mut secs = 0;
+        for amount in left.split_whitespace() {
+            let unit = amount.chars().last()?;
+            let n: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+            secs += n * match unit {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+        }
+        Some(Duration::from_secs(secs))
+    } else {
+        let (_, rest) = text.split_once("please wait ")?;
+        let minutes = match rest.split_whitespace().next()? {
+            "one" => 1,
+            n => n.parse().ok()?,
+        };
+        Some(Duration::from_secs(minutes * 60))
+    }
+}
+
+/// Why an answer is not submitted again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The answer was submitted before and was wrong.
+    Repeated(Option<Hint>),
+    /// The answer is at least as high as the given answer which was too high.
+    TooHigh(String),
+    /// The answer is at most as low as the given answer which was too low.
+    TooLow(String),
+    /// The website asked to wait this much longer before the next answer.
+    Wait(Duration),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::Repeated(None) => write!(f, "it was wrong before"),
+            Rejection::Repeated(Some(hint)) => write!(f, "it was {} before", hint),
+            Rejection::TooHigh(guess) => write!(f, "`{}` was too high already", guess),
+            Rejection::TooLow(guess) => write!(f, "`{}` was too low already", guess),
+            Rejection::Wait(wait) => write!(f, "wait {:?} before submitting again", wait),
+        }
+    }
+}
+
+/// The wrong answers which were submitted for a year, usually stored as
+/// `guesses.toml` next to its `answers.toml`, and until when the website asked
+/// to wait before submitting another answer of a part.
+///
+/// Like [Answers](crate::Answers) only a small subset of TOML is supported, a
+/// table per part with arrays of wrong answers grouped by their hint and the
+/// end of the wait in seconds since the Unix epoch:
+///
+/// ```toml
+/// [09.part1]
+/// too_high = [1234]
+/// too_low = [12, 34]
+/// wrong = ["abc"]
+/// wait_until = 1608789660  # 2020-12-24T06:01:00Z
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Guesses(BTreeMap<(u8, Part), PartGuesses>);
+
+/// The wrong answers of a part and until when to wait.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct PartGuesses {
+    wrong: Vec<Guess>,
+    wait_until: Option<u64>,
+}
+
+/// A wrong answer together with its hint.
+type Guess = (String, Option<Hint>);
+
+impl Guesses {
+    /// Reads the guesses file at the given path. A missing file has no
+    /// guesses.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the guesses to the given path with a header comment.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(
+            path,
+            format!(
+                "# Wrong answers rejected by the website, `aoc submit` never submits them again\n\
+                 # and waits until `wait_until` before submitting another answer of a part.\n\n{}",
+                self
+            ),
+        )
+    }
+
+    /// Parses the contents of a guesses file.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut guesses = Self::default();
+        let mut table = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let invalid = |msg: &str| Error::parse(format!("line {}: {}", i + 1, msg));
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            } else if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let (day, part) = name
+                    .split_once('.')
+                    .ok_or_else(|| invalid("expected a `[day.part]` table"))?;
+                let day = match day.trim().parse() {
+                    Ok(d @ 1..=25) => d,
+                    _ => return Err(invalid("expected a day from 01 to 25")),
+                };
+                let part = match part.trim() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(invalid("expected `part1` or `part2`")),
+                };
+                table = Some((day, part));
+            } else if let Some((key, value)) = line.split_once('=') {
+                let (day, part) = table.ok_or_else(|| invalid("guesses outside of a part"))?;
+                let hint = match key.trim() {
+                    "too_high" => Some(Hint::TooHigh),
+                    "too_low" => Some(Hint::TooLow),
+                    "wrong" => None,
+                    "wait_until" => {
+                        let secs = value
+                            .trim()
+                            .parse()
+                            .map_err(|_| invalid("expected seconds since the Unix epoch"))?;
+                        guesses.0.entry((day, part)).or_default().wait_until = Some(secs);
+                        continue;
+                    }
+                    _ => {
+                        return Err(invalid(
+                            "expected `too_high`, `too_low`, `wrong` or `wait_until`",
+                        ))
+                    }
+                };
+                for guess in parse_array(value.trim()).ok_or_else(|| invalid("invalid array"))? {
+                    guesses.add(day, part, guess, hint);
+                }
+            } else {
+                return Err(invalid("expected a `[day.part]` or `key = [answers]`"));
+            }
+        }
+
+        Ok(guesses)
+    }
+
+    /// Remembers a wrong answer.
+    pub fn add(&mut self, day: u8, part: Part, answer: impl Into<String>, hint: Option<Hint>) {
+        self.0
+            .entry((day, part))
+            .or_default()
+            .wrong
+            .push((answer.into(), hint));
+    }
+
+    /// Remembers that the website asked to wait the given time after `now`
+    /// before submitting another answer of the part.
+    pub fn wait(&mut self, day: u8, part: Part, now: SystemTime, wait: Duration) {
+        let until = (now + wait).duration_since(UNIX_EPOCH).unwrap_or_default();
+        // Round up, so the wait is never cut short
+        let secs = until.as_secs() + u64::from(until.subsec_nanos() > 0);
+        self.0.entry((day, part)).or_default().wait_until = Some(secs);
+    }
+
+    /// Returns why the answer should not be submitted at `now`, if the website
+    /// asked to wait longer, the same answer was wrong before or a wrong number
+    /// bounds it.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: SystemTime) -> Option<Rejection> {
+        let PartGuesses { wrong, wait_until } = self.0.get(&(day, part))?;
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        match *wait_until {
+            Some(until) if until > now => {
+                return Some(Rejection::Wait(Duration::from_secs(until - now)));
+            }
+            _ => {}
+        }
+
+        if let Some((_, hint)) = wrong.iter().find(|(guess, _)| guess == answer) {
+            return Some(Rejection::Repeated(*hint));
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+        wrong.iter().find_map(|(guess, hint)| {
+            let n: i128 = guess.parse().ok()?;
+            match hint {
+                Some(Hint::TooHigh) if answer >= n => Some(Rejection::TooHigh(guess.clone())),
+                Some(Hint::TooLow) if answer <= n => Some(Rejection::TooLow(guess.clone())),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Writes the guesses back in the format [Guesses::parse](Guesses::parse)
+/// expects.
+impl Display for Guesses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, ((day, part), PartGuesses { wrong, wait_until })) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let part = match part {
+                Part::One => "part1",
+                Part::Two => "part2",
+            };
+            writeln!(f, "[{:02}.{}]", day, part)?;
+
+            for (key, hint) in [
+                ("too_high", Some(Hint::TooHigh)),
+                ("too_low", Some(Hint::TooLow)),
+                ("wrong", None),
+            ] {
+                let values: Vec<_> = wrong
+                    .iter()
+                    .filter(|(_, h)| *h == hint)
+                    .map(|(guess, _)| format_value(guess))
+                    .collect();
+                if !values.is_empty() {
+                    writeln!(f, "{} = [{}]", key, values.join(", "))?;
+                }
+            }
+            if let Some(secs) = wait_until {
+                let date = utc_date(UNIX_EPOCH + Duration::from_secs(*secs));
+                writeln!(f, "wait_until = {}  # {}", secs, date)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats an answer as an integer if it is one, otherwise as a string.
+fn format_value(value: &str) -> String {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Parses an array of strings or integers on a single line.
+fn parse_array(value: &str) -> Option<Vec<String>> {
+    let items = value.strip_prefix('[')?.strip_suffix(']')?;
+
+    let mut values = Vec::new();
+    let mut start = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in items.char_indices().chain(Some((items.len(), ','))) {
+        match c {
+            '\\' if in_string => {
+                escaped = !escaped;
+                continue;
+            }
+            '"' if !escaped => in_string = !in_string,
+            ',' if !in_string => {
+                let item = items[start..i].trim();
+                // A trailing comma is allowed
+                if !item.is_empty() || i < items.len() {
+                    values.push(parse_value(item)?);
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+        escaped = false;
+    }
+    Some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcome_parse_works() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too high. \
+                 Please wait one minute before trying again."
+            )),
+            Some(Outcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer. please wait 5 minutes before trying again."
+            )),
+            Some(Outcome::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            })
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again. You have 4m 32s left to wait."
+            )),
+            Some(Outcome::Wait(Duration::from_secs(272)))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Some(Outcome::AlreadySolved)
+        );
+        assert_eq!(Outcome::parse("<html>Log in</html>"), None);
+    }
+
+    #[test]
+    fn guesses_check_works() {
+        let mut guesses = Guesses::default();
+        guesses.add(9, Part::One, "100", Some(Hint::TooHigh));
+        guesses.add(9, Part::One, "10", Some(Hint::TooLow));
+        guesses.add(9, Part::One, "50", None);
+
+        assert_eq!(
+            guesses.check(9, Part::One, "100", UNIX_EPOCH),
+            Some(Rejection::Repeated(Some(Hint::TooHigh)))
+        );
+        assert_eq!(
+            guesses.check(9, Part::One, "50", UNIX_EPOCH),
+            Some(Rejection::Repeated(None))
+        );
+        assert_eq!(
+            guesses.check(9, Part::One, "120", UNIX_EPOCH),
+            Some(Rejection::TooHigh("100".into()))
+        );
+        assert_eq!(
+            guesses.check(9, Part::One, "-3", UNIX_EPOCH),
+            Some(Rejection::TooLow("10".into()))
+        );
+        assert_eq!(guesses.check(9, Part::One, "42", UNIX_EPOCH), None);
+        assert_eq!(guesses.check(9, Part::Two, "100", UNIX_EPOCH), None);
+    }
+
+    #[test]
+    fn guesses_wait_works() {
+        let now = UNIX_EPOCH + Duration::from_millis(1_608_789_600_500);
+        let mut guesses = Guesses::default();
+        guesses.wait(9, Part::One, now, Duration::from_secs(60));
+
+        assert_eq!(
+            guesses.check(9, Part::One, "42", now),
+            Some(Rejection::Wait(Duration::from_secs(61)))
+        );
+        assert_eq!(
+            guesses.check(9, Part::One, "42", now + Duration::from_secs(30)),
+            Some(Rejection::Wait(Duration::from_secs(31)))
+        );
+        assert_eq!(
+            guesses.check(9, Part::One, "42", now + Duration::from_secs(61)),
+            None
+        );
+        assert_eq!(guesses.check(9, Part::Two, "42", now), None);
+
+        let s = guesses.to_string();
+        assert_eq!(
+            s,
+            "[09.part1]\nwait_until = 1608789661  # 2020-12-24T06:01:01Z\n"
+        );
+        assert_eq!(Guesses::parse(&s), Ok(guesses));
+        assert!(Guesses::parse("[09.part1]\nwait_until = soon").is_err());
+    }
+
+    #[test]
+    fn guesses_display_roundtrips() {
+        let mut guesses = Guesses::default();
+        guesses.add(9, Part::Two, "1", Some(Hint::TooLow));
+        guesses.add(9, Part::Two, "2", Some(Hint::TooLow));
+        guesses.add(9, Part::Two, "a,\"b\"", None);
+        guesses.add(1, Part::One, "-5", Some(Hint::TooHigh));
+
+        let s = guesses.to_string();
+        assert_eq!(
+            s,
+            "[01.part1]\ntoo_high = [-5]\n\n[09.part2]\ntoo_low = [1, 2]\nwrong = [\"a,\\\"b\\\"\"]\n"
+        );
+        assert_eq!(Guesses::parse(&s), Ok(guesses));
+    }
+
+    #[test]
+    fn guesses_parse_invalid() {
+        assert!(Guesses::parse("too_high = [1]").is_err());
+        assert!(Guesses::parse("[09]").is_err());
+        assert!(Guesses::parse("[09.part3]").is_err());
+        assert!(Guesses::parse("[09.part1]\nhigh = [1]").is_err());
+        assert!(Guesses::parse("[09.part1]\nwrong = 1").is_err());
+        assert!(Guesses::parse("[09.part1]\nwrong = [1, two]").is_err());
+        assert_eq!(
+            Guesses::parse("[09.part1]\nwrong = [1, 2,]").map(|g| g.check(
+                9,
+                Part::One,
+                "2",
+                UNIX_EPOCH
+            )),
+            Ok(Some(Rejection::Repeated(None)))
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use crate::{Part, SolveReport, Status};
+
+/// A table of the durations of every day ordered by year and day, with a total
+/// row per year. Parts which were not solved show their status instead of a
+/// duration and are left out of the totals.
+pub struct Summary<'a> {
+    reports: Vec<&'a SolveReport>,
+}
+
+impl<'a> Summary<'a> {
+    pub fn new(reports: &'a [SolveReport]) -> Self {
+        let mut reports: Vec<_> = reports.iter().collect();
+        reports.sort_by_key(|report| (report.year, report.day, report.part));
+        Self { reports }
+    }
+
+    /// Returns the reports of every day, a day has a report per part.
+    fn days(&self) -> impl Iterator<Item = &[&'a SolveReport]> {
+        self.reports
+            .chunk_by(|a, b| (a.year, a.day) == (b.year, b.day))
+    }
+}
+
+/// The durations of a single row.
+#[derive(Default)]
+struct Row {
+    parse: Duration,
+    parts: [Option<Result<Duration, Status>>; 2],
+}
+
+impl Row {
+    fn add(&mut self, day: &[&SolveReport]) {
+        self.parse += day.first().map(|report| report.parse).unwrap_or_default();
+        for report in day {
+            let index = match report.part {
+                Part::One => 0,
+                Part::Two => 1,
+            };
+            let cell = match report.status {
+                Status::Solved => Ok(report.duration),
+                status => Err(status),
+            };
+
+            self.parts[index] = Some(match (self.parts[index], cell) {
+                (Some(Ok(total)), Ok(duration)) => Ok(total + duration),
+                (Some(Ok(total)), Err(_)) | (Some(Err(_)), Ok(total)) => Ok(total),
+                (Some(Err(status)), Err(_)) | (None, Err(status)) => Err(status),
+                (None, Ok(duration)) => Ok(duration),
+            });
+        }
+    }
+
+    fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts
+                .iter()
+                .filter_map(|part| part.and_then(Result::ok))
+                .sum::<Duration>()
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, year: &str, day: &str) -> fmt::Result {
+        let cell = |part: Option<Result<Duration, Status>>| match part {
+            Some(Ok(duration)) => format!("{:.3?}", duration),
+            Some(Err(status)) => status.to_string().to_uppercase(),
+            None => "-".to_string(),
+        };
+
+        writeln!(
+            f,
+            "{:>5} | {:>5} | {:>12} | {:>13} | {:>13} | {:>12}",
+            year,
+            day,
+            format!("{:.3?}", self.parse),
+            cell(self.parts[0]),
+            cell(self.parts[1]),
+            format!("{:.3?}", self.total())
+        )
+    }
+}
+
+impl Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5} | {:>5} | {:>12} | {:>13} | {:>13} | {:>12}",
+            "Year", "Day", "Parse", "Part 1", "Part 2", "Total"
+        )?;
+        writeln!(f, "{}", "-".repeat(78))?;
+
+        let mut years = 0;
+        let mut total = Row::default();
+        let mut days = self.days().peekable();
+        while let Some(day) = days.next() {
+            let year = day[0].year;
+            let mut year_total = Row::default();
+            let mut day = Some(day);
+
+            while let Some(reports) = day {
+                let mut row = Row::default();
+                row.add(reports);
+                row.write(f, &year.to_string(), &format!("{:02}", reports[0].day))?;
+
+                year_total.add(reports);
+                total.add(reports);
+                day = days.next_if(|next| next[0].year == year);
+            }
+
+            year_total.write(f, &year.to_string(), "Total")?;
+            writeln!(f, "{}", "-".repeat(78))?;
+            years += 1;
+        }
+
+        if years > 1 {
+            total.write(f, "All", "Total")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(year: u16, day: u8, part: Part, micros: u64, status: Status) -> SolveReport {
+        SolveReport {
+            year,
+            day,
+            part,
+            answer: String::new(),
+            parse: Duration::from_micros(1),
+            duration: Duration::from_micros(micros),
+            allocs: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn summary_has_totals_per_year() {
+        let reports = [
+            report(2020, 9, Part::One, 10, Status::Solved),
+            report(2020, 9, Part::Two, 20, Status::Solved),
+            report(2015, 1, Part::One, 30, Status::Solved),
+            report(2015, 1, Part::Two, 40, Status::NoAnswer),
+        ];
+
+        assert_eq!(
+            Summary::new(&reports).to_string(),
+            " Year |   Day |        Parse |        Part 1 |        Part 2 |        Total\n\
+            ------------------------------------------------------------------------------\n\
+            \x202015 |    01 |      1.000µs |      30.000µs |     NO_ANSWER |     31.000µs\n\
+            \x202015 | Total |      1.000µs |      30.000µs |     NO_ANSWER |     31.000µs\n\
+            ------------------------------------------------------------------------------\n\
+            \x202020 |    09 |      1.000µs |      10.000µs |      20.000µs |     31.000µs\n\
+            \x202020 | Total |      1.000µs |      10.000µs |      20.000µs |     31.000µs\n\
+            ------------------------------------------------------------------------------\n\
+            \x20 All | Total |      2.000µs |      40.000µs |      20.000µs |     62.000µs\n"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::{Display, Write};
+
+use crate::{Part, Solution};
+
+/// Input lines shown in the message of a failed check.
+const PREVIEW_LINES: usize = 10;
+
+/// Solves a part of the input and panics if the answer is not the expected
+/// answer. The message shows the input and a diff of the expected (`-`) and
+/// actual (`+`) answer.
+///
+/// Answers are compared by how they are displayed, so any expected answer
+/// which displays the same as the answer passes.
+#[track_caller]
+pub fn check_answer<S: Solution>(solution: &S, part: Part, input: &str, expected: impl Display) {
+    let answer = solution.parse(input).and_then(|parsed| match part {
+        Part::One => solution.part1(&parsed).map(|ans| ans.to_string()),
+        Part::Two => solution.part2(&parsed).map(|ans| ans.to_string()),
+    });
+    let expected = expected.to_string();
+
+    match answer {
+        Ok(answer) if answer == expected => {}
+        Ok(answer) => panic!(
+            "{} gave the wrong answer\n{}answer:\n{}",
+            part,
+            preview(input),
+            diff(&expected, &answer)
+        ),
+        Err(err) => panic!(
+            "{} failed: {}\n{}answer:\n- {}\n",
+            part,
+            err,
+            preview(input),
+            expected
+        ),
+    }
+}
+
+/// Shows the first lines of the input.
+fn preview(input: &str) -> String {
+    let lines = input.lines().count();
+    let mut s = format!("input ({} lines):\n", lines);
+    for line in input.lines().take(PREVIEW_LINES) {
+        let _ = writeln!(s, "  | {}", line);
+    }
+    if lines > PREVIEW_LINES {
+        let _ = writeln!(s, "  | ... ({} more lines)", lines - PREVIEW_LINES);
+    }
+    s
+}
+
+/// Compares the answers line by line, marking lines only in the expected
+/// answer with `-` and lines only in the actual answer with `+`.
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual): (Vec<_>, Vec<_>) =
+        (expected.lines().collect(), actual.lines().collect());
+    let mut s = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => {
+                let _ = writeln!(s, "  {}", e);
+            }
+            (e, a) => {
+                if let Some(e) = e {
+                    let _ = writeln!(s, "- {}", e);
+                }
+                if let Some(a) = a {
+                    let _ = writeln!(s, "+ {}", a);
+                }
+            }
+        }
+    }
+    s
+}
+
+/// Generates a test for every named table of `input => expected answer` rows
+/// of a part, which are checked with [check_answer](crate::check_answer).
+///
+/// Example inputs can be read from `inputs/examples/` of the crate with
+/// [example](crate::example).
+///
+/// ```
+/// use aoc_lib::{Part1, Part2};
+///
+/// struct Length;
+///
+/// impl Part1 for Length {
+///     type A = usize;
+///
+///     fn solve(&self, input: &str) -> Self::A {
+///         input.len()
+///     }
+/// }
+///
+/// impl Part2 for Length {
+///     type B = usize;
+///
+///     fn solve(&self, input: &str) -> Self::B {
+///         input.lines().count()
+///     }
+/// }
+///
+/// aoc_lib::test_table! {
+///     Length;
+///     part1_example: Part1 {
+///         "abc" => 3,
+///         "" => 0,
+///     },
+///     part2_example: Part2 {
+///         "a\nb" => 2,
+///     },
+/// }
+/// ```
+#[macro_export]
+macro_rules! test_table {
+    (@part Part1) => {
+        $crate::Part::One
+    };
+    (@part Part2) => {
+        $crate::Part::Two
+    };
+    (
+        $solution:expr;
+        $($name:ident: $part:ident { $($input:expr => $expected:expr),+ $(,)? }),+ $(,)?
+    ) => {
+        $(
+            #[test]
+            fn $name() {
+                let solution = $solution;
+                $(
+                    $crate::check_answer(
+                        &solution,
+                        $crate::test_table!(@part $part),
+                        $input,
+                        $expected,
+                    );
+                )+
+            }
+        )+
+    };
+}
+
+/// Includes an example input from the `inputs/examples/` directory of the
+/// crate which uses the macro.
+#[macro_export]
+macro_rules! example {
+    ($name:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/inputs/examples/",
+            $name
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part1, Part2};
+
+    struct Sum;
+
+    impl Part1 for Sum {
+        type A = u32;
+
+        fn solve(&self, input: &str) -> Self::A {
+            input.lines().map(|line| line.parse::<u32>().unwrap()).sum()
+        }
+    }
+
+    impl Part2 for Sum {
+        type B = String;
+
+        fn solve(&self, input: &str) -> Self::B {
+            input.lines().rev().collect::<Vec<_>>().join("\n")
+        }
+    }
+
+    crate::test_table! {
+        Sum;
+        table_part1: Part1 {
+            "1\n2" => 3,
+            "40" => "40",
+        },
+        table_part2: Part2 {
+            "a\nb" => "b\na",
+        },
+    }
+
+    #[test]
+    #[should_panic(expected = "Part1 gave the wrong answer\n\
+                               input (2 lines):\n  | 1\n  | 2\n\
+                               answer:\n- 4\n+ 3\n")]
+    fn check_answer_shows_diff() {
+        check_answer(&Sum, Part::One, "1\n2", 4);
+    }
+
+    #[test]
+    fn preview_is_short() {
+        let input: String = (0..12).map(|i| format!("{}\n", i)).collect();
+        let preview = preview(&input);
+
+        assert!(preview.starts_with("input (12 lines):\n  | 0\n"));
+        assert!(preview.ends_with("  | 9\n  | ... (2 more lines)\n"));
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(diff("a\nb\nc", "a\nx"), "  a\n- b\n+ x\n- c\n");
+    }
+}
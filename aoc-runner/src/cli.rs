@@ -0,0 +1,809 @@
+use std::{path::PathBuf, time::Duration};
+
+use aoc_lib::{BenchConfig, InputSource, Part};
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run <year> [day] [--input <path>]      Runs every day of a year or a single day
+            [--format <format>]
+            [--param <name>=<value>]...
+    aoc bench <year> [day] [--input <path>]    Benchmarks every part of a year or a single day
+              [--warm-up <secs>] [--budget <secs>]
+              [--param <name>=<value>]...
+    aoc watch <year> <day>                     Runs a day again whenever its input or the
+              [--example <path>]               example input changes
+              [--interval <secs>]
+              [--param <name>=<value>]...
+    aoc readme [--warm-up <secs>]              Benchmarks every day and rewrites the tables
+               [--budget <secs>]               in `README.md`
+               [--machine <text>]
+    aoc compare <baseline> [commit]            Compares the benchmarks of a commit, the last
+                [--threshold <percent>]        run by default, with those of a baseline
+    aoc all [year] [--input <path>]            Runs every day of every year or a single year
+            [--jobs <n>] [--timeout <secs>]    in parallel and prints a summary
+    aoc verify [year] [day] [--input <path>]   Checks the answers against `answers.toml`
+    aoc check [year] [day] [--input <path>]    Checks that every implementation of a part
+                                               gives the same answer
+    aoc fetch <year> [day]                     Downloads the missing inputs of every day of
+                                               a year or a single day into `inputs/`
+    aoc submit <year> <day> <part> [answer]    Submits the answer of a part, solving it
+               [--input <path>]                when no answer was given
+    aoc new <year> <day> [--domain]            Generates and registers a new day, and its
+                                               year if it is new
+    aoc list                                   Lists every registered solution and its
+                                               parameters
+
+Options:
+    --input <path>      Reads the input from a file, a directory with a file per
+                        day (e.g. `09.txt`) or stdin when given `-`. Defaults to
+                        the input embedded in the binary.
+    --format <format>   Prints the results as `text`, `json` or `csv`. Defaults
+                        to `text`.
+    --jobs <n>          Amount of threads to run days on. Defaults to the
+                        available parallelism.
+    --timeout <secs>    Stops waiting for parsing or a part after the given time
+                        and reports it as a timeout.
+    --warm-up <secs>    Time spent on unmeasured runs per part. Defaults to 0.5.
+    --budget <secs>     Time spent on measured runs per part. Defaults to 3.
+    --example <path>    Also runs the watched day on an example input.
+    --interval <secs>   Time between checks for changed inputs. Defaults to 0.5.
+    --threshold <percent>
+                        Slowdown of a part compared to the baseline above which
+                        it is flagged. Defaults to 10.
+    --machine <text>    Describes the machine in `README.md` instead of the
+                        detected CPU and operating system.
+    --domain            Also generates a `domain` module for the new day.
+    --param <name>=<value>
+                        Overrides a parameter of a single day, like the amount
+                        of rounds to run for an example input. Can be given
+                        more than once.
+
+Environment:
+    AOC_SESSION         The session cookie used by `fetch` and `submit`,
+                        overrides `session` in the config file.
+    AOC_URL             The website used by `fetch` and `submit`, overrides
+                        `url` in the config file. Defaults to
+                        https://adventofcode.com.
+    AOC_CONFIG          The config file. Defaults to `aoc/config.toml` in the
+                        config directory of the user.";
+
+/// How the results of a run are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// A command given to the `aoc` binary.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    /// Runs every day of a year or a single day.
+    Run {
+        year: u16,
+        day: Option<u8>,
+        input: InputSource,
+        format: Format,
+        params: Vec<(String, String)>,
+    },
+    /// Benchmarks every part of a year or a single day.
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        input: InputSource,
+        config: BenchConfig,
+        params: Vec<(String, String)>,
+    },
+    /// Runs a day again whenever its input or the example input changes.
+    Watch {
+        year: u16,
+        day: u8,
+        example: Option<PathBuf>,
+        interval: Duration,
+        params: Vec<(String, String)>,
+    },
+    /// Benchmarks every day and rewrites the benchmark tables of the README.
+    Readme {
+        config: BenchConfig,
+        machine: Option<String>,
+    },
+    /// Compares the benchmarks of a commit, or of the last run, with those of a
+    /// baseline commit.
+    Compare {
+        baseline: String,
+        commit: Option<String>,
+        threshold: f64,
+    },
+    /// Runs every day of every year or a single year in parallel.
+    All {
+        year: Option<u16>,
+        input: InputSource,
+        jobs: usize,
+        timeout: Option<Duration>,
+    },
+    /// Checks the answers of every year, a single year or a single day
+    /// against the known answers.
+    Verify {
+        year: Option<u16>,
+        day: Option<u8>,
+        input: InputSource,
+    },
+    /// Checks whether every implementation of a part of every year, a single
+    /// year or a single day gives the same answer.
+    Check {
+        year: Option<u16>,
+        day: Option<u8>,
+        input: InputSource,
+    },
+    /// Downloads the missing inputs of every day of a year or a single day.
+    Fetch { year: u16, day: Option<u8> },
+    /// Submits the given answer of a part, or the answer of the solution.
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: Option<String>,
+        input: InputSource,
+    },
+    /// Generates and registers a new day, with a `domain` module if asked.
+    New { year: u16, day: u8, domain: bool },
+    /// Lists every registered solution.
+    List,
+}
+
+impl Command {
+    /// Parses the command from the program arguments, excluding the program
+    /// name itself.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut args = Args(args.into_iter().collect());
+
+        match args.0.first().map(String::as_str) {
+            Some("run") => {
+                let format = match args.option("--format")?.as_deref() {
+                    None | Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("csv") => Format::Csv,
+                    Some(format) => return Err(format!("unknown format `{}`", format)),
+                };
+
+                let params = args.params()?;
+                let (year, day, input) = args.target()?;
+                Ok(Command::Run {
+                    year,
+                    day,
+                    input,
+                    format,
+                    params: single_day(day, params)?,
+                })
+            }
+            Some("bench") => {
+                let config = args.bench_config()?;
+                let params = args.params()?;
+                let (year, day, input) = args.target()?;
+                Ok(Command::Bench {
+                    year,
+                    day,
+                    input,
+                    config,
+                    params: single_day(day, params)?,
+                })
+            }
+            Some("watch") => {
+                let example = args.option("--example")?.map(PathBuf::from);
+                let interval = match args.option("--interval")? {
+                    Some(secs) => parse_secs(&secs)?,
+                    None => Duration::from_millis(500),
+                };
+                let params = args.params()?;
+                match args.positionals()?.as_slice() {
+                    [_, year, day] => Ok(Command::Watch {
+                        year: parse_year(year)?,
+                        day: parse_day(day)?,
+                        example,
+                        interval,
+                        params,
+                    }),
+                    _ => Err(USAGE.to_string()),
+                }
+            }
+            Some("readme") => {
+                let config = args.bench_config()?;
+                let machine = args.option("--machine")?;
+                match args.positionals()?.as_slice() {
+                    [_] => Ok(Command::Readme { config, machine }),
+                    _ => Err(USAGE.to_string()),
+                }
+            }
+            Some("compare") => {
+                let threshold = match args.option("--threshold")? {
+                    Some(percent) => match percent.parse::<f64>() {
+                        Ok(percent) if percent.is_finite() && percent >= 0.0 => percent,
+                        _ => return Err(format!("invalid threshold `{}`", percent)),
+                    },
+                    None => 10.0,
+                };
+                match args.positionals()?.as_slice() {
+                    [_, baseline, commit @ ..] if commit.len() <= 1 => Ok(Command::Compare {
+                        baseline: baseline.clone(),
+                        commit: commit.first().cloned(),
+                        threshold,
+                    }),
+                    _ => Err(USAGE.to_string()),
+                }
+            }
+            Some("all") => {
+                let jobs = match args.option("--jobs")? {
+                    Some(jobs) => match jobs.parse() {
+                        Ok(jobs) if jobs > 0 => jobs,
+                        _ => return Err(format!("invalid amount of jobs `{}`", jobs)),
+                    },
+                    None => aoc_lib::default_jobs(),
+                };
+                let timeout = args
+                    .option("--timeout")?
+                    .map(|secs| parse_secs(&secs))
+                    .transpose()?;
+                let input = args
+                    .option("--input")?
+                    .map(|arg| InputSource::from_arg(&arg))
+                    .unwrap_or_default();
+
+                match args.positionals()?.as_slice() {
+                    _ if matches!(input, InputSource::File(_) | InputSource::Stdin) => {
+                        Err("every day can only read its input from a directory".to_string())
+                    }
+                    [_] => Ok(Command::All {
+                        year: None,
+                        input,
+                        jobs,
+                        timeout,
+                    }),
+                    [_, year] => Ok(Command::All {
+                        year: Some(parse_year(year)?),
+                        input,
+                        jobs,
+                        timeout,
+                    }),
+                    _ => Err(USAGE.to_string()),
+                }
+            }
+            Some("verify") if args.0.len() == 1 => Ok(Command::Verify {
+                year: None,
+                day: None,
+                input: InputSource::Embedded,
+            }),
+            Some("verify") => {
+                let (year, day, input) = args.target()?;
+                Ok(Command::Verify {
+                    year: Some(year),
+                    day,
+                    input,
+                })
+            }
+            Some("check") if args.0.len() == 1 => Ok(Command::Check {
+                year: None,
+                day: None,
+                input: InputSource::Embedded,
+            }),
+            Some("check") => {
+                let (year, day, input) = args.target()?;
+                Ok(Command::Check {
+                    year: Some(year),
+                    day,
+                    input,
+                })
+            }
+            Some("fetch") => match args.positionals()?.as_slice() {
+                [_, year] => Ok(Command::Fetch {
+                    year: parse_year(year)?,
+                    day: None,
+                }),
+                [_, year, day] => Ok(Command::Fetch {
+                    year: parse_year(year)?,
+                    day: Some(parse_day(day)?),
+                }),
+                _ => Err(USAGE.to_string()),
+            },
+            Some("submit") => {
+                let input = args
+                    .option("--input")?
+                    .map(|arg| InputSource::from_arg(&arg))
+                    .unwrap_or_default();
+
+                match args.positionals()?.as_slice() {
+                    [_, year, day, part, answer @ ..] if answer.len() <= 1 => Ok(Command::Submit {
+                        year: parse_year(year)?,
+                        day: parse_day(day)?,
+                        part: parse_part(part)?,
+                        answer: answer.first().cloned(),
+                        input,
+                    }),
+                    _ => Err(USAGE.to_string()),
+                }
+            }
+            Some("new") => {
+                let domain = args.flag("--domain");
+                match args.positionals()?.as_slice() {
+                    [_, year, day] => Ok(Command::New {
+                        year: parse_year(year)?,
+                        day: parse_day(day)?,
+                        domain,
+                    }),
+                    _ => Err(USAGE.to_string()),
+                }
+            }
+            Some("list") => match args.positionals()?.as_slice() {
+                [_] => Ok(Command::List),
+                _ => Err(USAGE.to_string()),
+            },
+            _ => Err(USAGE.to_string()),
+        }
+    }
+}
+
+/// The program arguments from which options are taken until only positional
+/// arguments remain.
+struct Args(Vec<String>);
+
+impl Args {
+    /// Takes the value of an option given as `--name <value>` or
+    /// `--name=<value>`.
+    fn option(&mut self, name: &str) -> Result<Option<String>, String> {
+        let prefix = format!("{}=", name);
+
+        match self.0.iter().position(|arg| arg == name) {
+            Some(index) if index + 1 < self.0.len() => {
+                let value = self.0.remove(index + 1);
+                self.0.remove(index);
+                Ok(Some(value))
+            }
+            Some(_) => Err(format!("missing value for `{}`", name)),
+            None => Ok(self
+                .0
+                .iter()
+                .position(|arg| arg.starts_with(&prefix))
+                .map(|index| self.0.remove(index)[prefix.len()..].to_string())),
+        }
+    }
+
+    /// Takes the `--warm-up` and `--budget` options of a benchmark.
+    fn bench_config(&mut self) -> Result<BenchConfig, String> {
+        let mut config = BenchConfig::default();
+        if let Some(secs) = self.option("--warm-up")? {
+            config.warm_up = parse_secs(&secs)?;
+        }
+        if let Some(secs) = self.option("--budget")? {
+            config.budget = parse_secs(&secs)?;
+        }
+        Ok(config)
+    }
+
+    /// Takes every `--param <name>=<value>` option.
+    fn params(&mut self) -> Result<Vec<(String, String)>, String> {
+        let mut params = Vec::new();
+        while let Some(param) = self.option("--param")? {
+            params.push(aoc_lib::parse_param(&param).map_err(|err| err.to_string())?);
+        }
+        Ok(params)
+    }
+
+    /// Takes a flag given as `--name`, returning whether it was given.
+    fn flag(&mut self, name: &str) -> bool {
+        let len = self.0.len();
+        self.0.retain(|arg| arg != name);
+        self.0.len() < len
+    }
+
+    /// Takes the input option and returns the year and optional day given as
+    /// the remaining positional arguments.
+    fn target(mut self) -> Result<(u16, Option<u8>, InputSource), String> {
+        let input = self
+            .option("--input")?
+            .map(|arg| InputSource::from_arg(&arg))
+            .unwrap_or_default();
+
+        match self.positionals()?.as_slice() {
+            [_, _] if matches!(input, InputSource::File(_) | InputSource::Stdin) => {
+                Err("a whole year can only read its input from a directory".to_string())
+            }
+            [_, year] => Ok((parse_year(year)?, None, input)),
+            [_, year, day] => Ok((parse_year(year)?, Some(parse_day(day)?), input)),
+            _ => Err(USAGE.to_string()),
+        }
+    }
+
+    /// Returns the remaining positional arguments, failing on unknown options.
+    fn positionals(self) -> Result<Vec<String>, String> {
+        match self.0.iter().find(|arg| arg.starts_with("--")) {
+            Some(arg) => Err(format!("unknown option `{}`\n\n{}", arg, USAGE)),
+            None => Ok(self.0),
+        }
+    }
+}
+
+/// Returns the parameters if they are given for a single day.
+fn single_day(
+    day: Option<u8>,
+    params: Vec<(String, String)>,
+) -> Result<Vec<(String, String)>, String> {
+    if day.is_none() && !params.is_empty() {
+        return Err("parameters can only be given for a single day".to_string());
+    }
+    Ok(params)
+}
+
+fn parse_year(year: &str) -> Result<u16, String> {
+    year.parse().map_err(|_| format!("invalid year `{}`", year))
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day `{}`, expected 1 to 25", day)),
+    }
+}
+
+fn parse_part(part: &str) -> Result<Part, String> {
+    match part {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part `{}`, expected 1 or 2", part)),
+    }
+}
+
+fn parse_secs(secs: &str) -> Result<Duration, String> {
+    secs.parse()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("invalid amount of seconds `{}`", secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_run() {
+        assert_eq!(
+            parse(&["run", "2020", "20"]),
+            Ok(Command::Run {
+                year: 2020,
+                day: Some(20),
+                input: InputSource::Embedded,
+                format: Format::Text,
+                params: vec![],
+            })
+        );
+        assert_eq!(
+            parse(&["run", "2015", "01"]),
+            Ok(Command::Run {
+                year: 2015,
+                day: Some(1),
+                input: InputSource::Embedded,
+                format: Format::Text,
+                params: vec![],
+            })
+        );
+        assert_eq!(
+            parse(&["run", "2015"]),
+            Ok(Command::Run {
+                year: 2015,
+                day: None,
+                input: InputSource::Embedded,
+                format: Format::Text,
+                params: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_run_input() {
+        assert_eq!(
+            parse(&["run", "2020", "9", "--input", "-"]),
+            Ok(Command::Run {
+                year: 2020,
+                day: Some(9),
+                input: InputSource::Stdin,
+                format: Format::Text,
+                params: vec![],
+            })
+        );
+        assert_eq!(
+            parse(&["run", "--input=mine.txt", "2020", "9"]),
+            Ok(Command::Run {
+                year: 2020,
+                day: Some(9),
+                input: InputSource::File("mine.txt".into()),
+                format: Format::Text,
+                params: vec![],
+            })
+        );
+        assert!(parse(&["run", "2020", "9", "--input"]).is_err());
+        assert!(parse(&["run", "2020", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn parse_run_format() {
+        assert_eq!(
+            parse(&["run", "2015", "--format", "csv"]),
+            Ok(Command::Run {
+                year: 2015,
+                day: None,
+                input: InputSource::Embedded,
+                format: Format::Csv,
+                params: vec![],
+            })
+        );
+        assert!(parse(&["run", "2015", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn parse_bench() {
+        assert_eq!(
+            parse(&["bench", "2020", "23", "--budget", "10", "--warm-up=0.25"]),
+            Ok(Command::Bench {
+                year: 2020,
+                day: Some(23),
+                input: InputSource::Embedded,
+                config: BenchConfig {
+                    warm_up: Duration::from_millis(250),
+                    budget: Duration::from_secs(10),
+                    ..BenchConfig::default()
+                },
+                params: vec![],
+            })
+        );
+        assert!(parse(&["bench", "2020", "--budget", "-1"]).is_err());
+        assert_eq!(
+            parse(&["bench", "2020", "--budget", "1e30"]),
+            Err("invalid amount of seconds `1e30`".to_string())
+        );
+        assert!(parse(&["bench", "2020", "--warm-up", "inf"]).is_err());
+    }
+
+    #[test]
+    fn parse_params() {
+        assert_eq!(
+            parse(&[
+                "run",
+                "2020",
+                "23",
+                "--param",
+                "part1_moves=10",
+                "--param=part2_moves=5"
+            ]),
+            Ok(Command::Run {
+                year: 2020,
+                day: Some(23),
+                input: InputSource::Embedded,
+                format: Format::Text,
+                params: vec![
+                    ("part1_moves".into(), "10".into()),
+                    ("part2_moves".into(), "5".into())
+                ],
+            })
+        );
+        assert!(parse(&["bench", "2020", "23", "--param", "moves"]).is_err());
+        assert!(parse(&["run", "2020", "--param", "moves=10"]).is_err());
+    }
+
+    #[test]
+    fn parse_watch() {
+        assert_eq!(
+            parse(&[
+                "watch",
+                "2020",
+                "23",
+                "--example",
+                "23.txt",
+                "--param=part1_moves=10"
+            ]),
+            Ok(Command::Watch {
+                year: 2020,
+                day: 23,
+                example: Some("23.txt".into()),
+                interval: Duration::from_millis(500),
+                params: vec![("part1_moves".into(), "10".into())],
+            })
+        );
+        assert_eq!(
+            parse(&["watch", "2015", "1", "--interval", "2"]),
+            Ok(Command::Watch {
+                year: 2015,
+                day: 1,
+                example: None,
+                interval: Duration::from_secs(2),
+                params: vec![],
+            })
+        );
+        assert!(parse(&["watch", "2015"]).is_err());
+        assert!(parse(&["watch", "2015", "1", "--input", "-"]).is_err());
+        assert!(parse(&["watch", "2015", "1", "--interval", "1e30"]).is_err());
+    }
+
+    #[test]
+    fn parse_readme() {
+        assert_eq!(
+            parse(&["readme", "--budget", "1", "--machine", "a toaster"]),
+            Ok(Command::Readme {
+                config: BenchConfig {
+                    budget: Duration::from_secs(1),
+                    ..BenchConfig::default()
+                },
+                machine: Some("a toaster".into()),
+            })
+        );
+        assert!(parse(&["readme", "2020"]).is_err());
+    }
+
+    #[test]
+    fn parse_compare() {
+        assert_eq!(
+            parse(&["compare", "0e82ca4"]),
+            Ok(Command::Compare {
+                baseline: "0e82ca4".into(),
+                commit: None,
+                threshold: 10.0,
+            })
+        );
+        assert_eq!(
+            parse(&["compare", "0e82ca4", "HEAD", "--threshold", "2.5"]),
+            Ok(Command::Compare {
+                baseline: "0e82ca4".into(),
+                commit: Some("HEAD".into()),
+                threshold: 2.5,
+            })
+        );
+        assert!(parse(&["compare"]).is_err());
+        assert!(parse(&["compare", "0e82ca4", "--threshold", "-1"]).is_err());
+    }
+
+    #[test]
+    fn parse_all() {
+        assert_eq!(
+            parse(&["all", "--jobs", "3"]),
+            Ok(Command::All {
+                year: None,
+                input: InputSource::Embedded,
+                jobs: 3,
+                timeout: None,
+            })
+        );
+        assert_eq!(
+            parse(&["all", "2020", "--jobs=1", "--timeout", "2.5"]),
+            Ok(Command::All {
+                year: Some(2020),
+                input: InputSource::Embedded,
+                jobs: 1,
+                timeout: Some(Duration::from_millis(2500)),
+            })
+        );
+        assert!(parse(&["all", "--jobs", "0"]).is_err());
+        assert!(parse(&["all", "2020", "9"]).is_err());
+        assert!(parse(&["all", "--input", "-"]).is_err());
+        assert!(parse(&["all", "--timeout", "1e30"]).is_err());
+    }
+
+    #[test]
+    fn parse_verify() {
+        assert_eq!(
+            parse(&["verify"]),
+            Ok(Command::Verify {
+                year: None,
+                day: None,
+                input: InputSource::Embedded,
+            })
+        );
+        assert_eq!(
+            parse(&["verify", "2015", "7"]),
+            Ok(Command::Verify {
+                year: Some(2015),
+                day: Some(7),
+                input: InputSource::Embedded,
+            })
+        );
+        assert!(parse(&["verify", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn parse_check() {
+        assert_eq!(
+            parse(&["check"]),
+            Ok(Command::Check {
+                year: None,
+                day: None,
+                input: InputSource::Embedded,
+            })
+        );
+        assert_eq!(
+            parse(&["check", "2015", "9", "--input", "mine.txt"]),
+            Ok(Command::Check {
+                year: Some(2015),
+                day: Some(9),
+                input: InputSource::File("mine.txt".into()),
+            })
+        );
+        assert!(parse(&["check", "2015", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn parse_fetch() {
+        assert_eq!(
+            parse(&["fetch", "2020"]),
+            Ok(Command::Fetch {
+                year: 2020,
+                day: None
+            })
+        );
+        assert_eq!(
+            parse(&["fetch", "2015", "4"]),
+            Ok(Command::Fetch {
+                year: 2015,
+                day: Some(4)
+            })
+        );
+        assert!(parse(&["fetch"]).is_err());
+        assert!(parse(&["fetch", "2015", "4", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn parse_submit() {
+        assert_eq!(
+            parse(&["submit", "2020", "9", "2", "35602097"]),
+            Ok(Command::Submit {
+                year: 2020,
+                day: 9,
+                part: Part::Two,
+                answer: Some("35602097".into()),
+                input: InputSource::Embedded,
+            })
+        );
+        assert_eq!(
+            parse(&["submit", "2015", "4", "1", "--input", "-"]),
+            Ok(Command::Submit {
+                year: 2015,
+                day: 4,
+                part: Part::One,
+                answer: None,
+                input: InputSource::Stdin,
+            })
+        );
+        assert!(parse(&["submit", "2015", "4"]).is_err());
+        assert!(parse(&["submit", "2015", "4", "3"]).is_err());
+    }
+
+    #[test]
+    fn parse_new() {
+        assert_eq!(
+            parse(&["new", "2021", "1"]),
+            Ok(Command::New {
+                year: 2021,
+                day: 1,
+                domain: false
+            })
+        );
+        assert_eq!(
+            parse(&["new", "--domain", "2021", "1"]),
+            Ok(Command::New {
+                year: 2021,
+                day: 1,
+                domain: true
+            })
+        );
+        assert!(parse(&["new", "2021"]).is_err());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "20x5"]).is_err());
+        assert!(parse(&["run", "2015", "26"]).is_err());
+        assert!(parse(&["run", "2015", "--verbose"]).is_err());
+        assert!(parse(&["list", "2015"]).is_err());
+    }
+}
@@ -0,0 +1,716 @@
+//! The `aoc` binary which is able to run every registered Advent of Code
+//! solution.
+
+mod cli;
+mod readme;
+mod scaffold;
+mod watch;
+
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime},
+};
+
+use aoc_lib::{
+    BenchConfig, BenchReport, Client, Config, Entry, Guesses, History, InputSource, Outcome, Part,
+    Record, Registry, Solver, Status, Summary, Verdict,
+};
+use cli::{Command, Format};
+
+/// The file in the workspace which every benchmark run is added to.
+const HISTORY: &str = "bench-history.csv";
+
+// Counts the allocations of every part, which `run` prints and reports
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOC: aoc_lib::CountingAlloc = aoc_lib::CountingAlloc;
+
+fn registry() -> Registry {
+    let mut registry = Registry::default();
+    aoc_2015::register(&mut registry);
+    aoc_2020::register(&mut registry);
+    registry
+}
+
+fn main() {
+    let result = Command::parse(env::args().skip(1)).and_then(|cmd| run(cmd, &registry()));
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run(command: Command, registry: &Registry) -> Result<(), String> {
+    match command {
+        Command::Run {
+            year,
+            day,
+            input,
+            format: Format::Text,
+            params,
+        } => {
+            for_each_entry(registry, year, day, &params, |entry| {
+                solve_print(entry, &input)
+            })?;
+        }
+        Command::Run {
+            year,
+            day,
+            input,
+            format,
+            params,
+        } => {
+            report(&configured(registry, year, day, &params)?, &input, format)?;
+        }
+        Command::Bench {
+            year,
+            day,
+            input,
+            config,
+            params,
+        } => {
+            // Only runs on the usual inputs are comparable with other runs
+            let history = (input == InputSource::Embedded && params.is_empty()).then(Recorder::new);
+            for_each_entry(registry, year, day, &params, |entry| {
+                bench(entry, &input, &config, history.as_ref())
+            })?;
+        }
+        Command::Watch {
+            year,
+            day,
+            example,
+            interval,
+            params,
+        } => {
+            let entry = configured(registry, year, Some(day), &params)?.remove(0);
+            let input = registry
+                .dir(year)
+                .ok_or_else(|| format!("{} has no directory with inputs", year))?
+                .join(format!("inputs/{:02}.txt", day));
+            let paths: Vec<_> = Some(input).into_iter().chain(example).collect();
+            watch::run(&entry, &paths, interval);
+        }
+        Command::Readme { config, machine } => {
+            let machine = machine.unwrap_or_else(readme::machine);
+            write_readme(registry, &config, &machine, &Recorder::new())?;
+        }
+        Command::Compare {
+            baseline,
+            commit,
+            threshold,
+        } => {
+            compare(&baseline, commit.as_deref(), threshold)?;
+        }
+        Command::All {
+            year,
+            input,
+            jobs,
+            timeout,
+        } => {
+            let entries: Vec<_> = match year {
+                Some(year) => entries(registry, year, None)?,
+                None => registry.iter().collect(),
+            };
+            run_all(&entries, &input, jobs, timeout)?;
+        }
+        Command::Verify { year, day, input } => {
+            let years = match year {
+                Some(year) => vec![year],
+                None => registry.years(),
+            };
+            verify(registry, &years, day, &input)?;
+        }
+        Command::Check { year, day, input } => {
+            let years = match year {
+                Some(year) => vec![year],
+                None => registry.years(),
+            };
+            check(registry, &years, day, &input)?;
+        }
+        Command::Fetch { year, day } => {
+            fetch(registry, year, day)?;
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            input,
+        } => {
+            submit(registry, year, day, part, answer, &input)?;
+        }
+        Command::New { year, day, domain } => {
+            let root = root();
+            let files =
+                scaffold::new_day(root, year, day, domain).map_err(|err| err.to_string())?;
+            for file in files {
+                println!(
+                    "wrote {}",
+                    file.strip_prefix(root).unwrap_or(&file).display()
+                );
+            }
+            println!("Fetch its input with `aoc fetch {} {}`", year, day);
+        }
+        Command::List => {
+            for entry in registry.iter() {
+                print!("{} {:02}", entry.year, entry.day);
+                for param in entry.params() {
+                    print!("  {}={}", param.name, param.value);
+                }
+                for part in Part::ALL.iter().copied() {
+                    let implementations = entry.implementations(part);
+                    if implementations.len() > 1 {
+                        let names: Vec<_> = implementations.iter().map(|imp| imp.name).collect();
+                        print!("  {}: {}", part, names.join(", "));
+                    }
+                }
+                println!();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the root directory of the workspace.
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is in a workspace")
+}
+
+/// Returns the entry of the given day, or every entry of the year if there is
+/// no day.
+fn entries(registry: &Registry, year: u16, day: Option<u8>) -> Result<Vec<&Entry>, String> {
+    match day {
+        Some(day) => registry
+            .get(year, day)
+            .map(|entry| vec![entry])
+            .ok_or_else(|| format!("{} day {:02} is not registered", year, day)),
+        None => {
+            let entries: Vec<_> = registry.year(year).collect();
+            if entries.is_empty() {
+                return Err(format!("{} has no registered days", year));
+            }
+            Ok(entries)
+        }
+    }
+}
+
+/// Like [entries], but with the given parameters overridden.
+fn configured(
+    registry: &Registry,
+    year: u16,
+    day: Option<u8>,
+    params: &[(String, String)],
+) -> Result<Vec<Entry>, String> {
+    entries(registry, year, day)?
+        .into_iter()
+        .map(|entry| {
+            entry
+                .with_params(params)
+                .map_err(|err| format!("{} {}", year, err))
+        })
+        .collect()
+}
+
+/// Calls `f` with the entry of the given day, or with every entry of the year
+/// under a header if there is no day. A failing day of a year does not stop the
+/// remaining days from running.
+fn for_each_entry<F>(
+    registry: &Registry,
+    year: u16,
+    day: Option<u8>,
+    params: &[(String, String)],
+    mut f: F,
+) -> Result<(), String>
+where
+    F: FnMut(&Entry) -> Result<(), String>,
+{
+    let entries = configured(registry, year, day, params)?;
+    if day.is_some() {
+        return f(&entries[0]);
+    }
+
+    let mut failed = 0;
+    for entry in &entries {
+        println!("{} Day {:02}", entry.year, entry.day);
+        if let Err(err) = f(entry) {
+            eprintln!("{}", err);
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} failed on {} day(s)", year, failed));
+    }
+    Ok(())
+}
+
+fn read_input(entry: &Entry, source: &InputSource) -> Result<Cow<'static, str>, String> {
+    entry.read_input(source).map_err(|err| {
+        format!(
+            "could not read the input of {} day {:02} from {}: {}",
+            entry.year, entry.day, source, err
+        )
+    })
+}
+
+/// Reads the input of the entry and prints the answers, reporting malformed
+/// input as an error instead of crashing.
+fn solve_print(entry: &Entry, source: &InputSource) -> Result<(), String> {
+    let input = read_input(entry, source)?;
+
+    entry
+        .solve_print(&input)
+        .map_err(|err| format!("{} {}", entry.year, err))
+}
+
+/// Solves every part of the entries and prints the reports in a machine
+/// readable format.
+fn report(entries: &[Entry], source: &InputSource, format: Format) -> Result<(), String> {
+    let mut reports = Vec::new();
+    for entry in entries {
+        let input = read_input(entry, source)?;
+        reports.extend(entry.reports(&input));
+    }
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    match format {
+        Format::Json => aoc_lib::write_json(&mut stdout, &reports),
+        Format::Csv => aoc_lib::write_csv(&mut stdout, &reports),
+        Format::Text => unreachable!("text is printed while solving"),
+    }
+    .and_then(|_| stdout.flush())
+    .map_err(|err| err.to_string())?;
+
+    let failed = reports
+        .iter()
+        .filter(|report| report.status != Status::Solved)
+        .count();
+    if failed > 0 {
+        return Err(format!("{} part(s) failed", failed));
+    }
+    Ok(())
+}
+
+/// Solves every part of the entries on a thread pool and prints a summary
+/// table followed by the errors of the parts which failed. Parsing and every
+/// part are stopped after the timeout if there is one.
+fn run_all(
+    entries: &[&Entry],
+    source: &InputSource,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let reports = aoc_lib::par_map(entries, jobs, |entry| {
+        read_input(entry, source).map(|input| entry.reports_within(&input, timeout))
+    })
+    .into_iter()
+    .collect::<Result<Vec<_>, _>>()?
+    .concat();
+
+    print!("{}", Summary::new(&reports));
+
+    let failed: Vec<_> = reports
+        .iter()
+        .filter(|report| report.status != Status::Solved)
+        .collect();
+    for report in &failed {
+        eprintln!(
+            "{} day {:02} {}: {}: {}",
+            report.year, report.day, report.part, report.status, report.answer
+        );
+    }
+
+    if !failed.is_empty() {
+        return Err(format!("{} part(s) failed", failed.len()));
+    }
+    Ok(())
+}
+
+/// Compares the answer of every part with the known answers of its year and
+/// prints the verdicts followed by a summary. Fails if any answer is wrong.
+fn verify(
+    registry: &Registry,
+    years: &[u16],
+    day: Option<u8>,
+    source: &InputSource,
+) -> Result<(), String> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for &year in years {
+        let answers = registry
+            .answers(year)
+            .map_err(|err| format!("could not load the answers of {}: {}", year, err))?;
+
+        for entry in entries(registry, year, day)? {
+            let input = read_input(entry, source)?;
+            for report in entry.reports(&input) {
+                let part = report.part;
+                let verdict = answers.verify(&report);
+                print!(
+                    "{} Day {:02} {}  {:<7}  {}",
+                    year, entry.day, part, verdict, report.answer
+                );
+
+                match verdict {
+                    Verdict::Pass => passed += 1,
+                    Verdict::Missing => missing += 1,
+                    Verdict::Fail => {
+                        print!(
+                            " (expected {})",
+                            answers.get(entry.day, part).unwrap_or_default()
+                        );
+                        failed += 1;
+                    }
+                }
+                println!();
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        return Err(format!("{} answer(s) are wrong", failed));
+    }
+    Ok(())
+}
+
+/// Solves every part which has alternative implementations with each of them
+/// and prints whether they agree. Fails if any implementations disagree.
+fn check(
+    registry: &Registry,
+    years: &[u16],
+    day: Option<u8>,
+    source: &InputSource,
+) -> Result<(), String> {
+    let (mut checked, mut differed) = (0, 0);
+
+    for &year in years {
+        for entry in entries(registry, year, day)? {
+            if !entry.has_alternatives() {
+                continue;
+            }
+
+            let input = read_input(entry, source)?;
+            for part in Part::ALL.iter().copied() {
+                let check = entry.cross_check(part, &input);
+                if check.answers.len() < 2 {
+                    continue;
+                }
+
+                println!("{} Day {:02} {}  {}", year, entry.day, part, check);
+                checked += 1;
+                if !check.agrees() {
+                    differed += 1;
+                }
+            }
+        }
+    }
+
+    if checked == 0 {
+        return Err("no part has alternative implementations".to_string());
+    }
+    println!("{} agreed, {} differed", checked - differed, differed);
+    if differed > 0 {
+        return Err(format!(
+            "the implementations of {} part(s) differ",
+            differed
+        ));
+    }
+    Ok(())
+}
+
+/// Downloads the inputs of the given day, or of every registered day of the
+/// year, which are not in the `inputs/` directory of the year yet.
+fn fetch(registry: &Registry, year: u16, day: Option<u8>) -> Result<(), String> {
+    let dir = registry
+        .dir(year)
+        .ok_or_else(|| format!("{} has no directory to store inputs in", year))?
+        .join("inputs");
+    let days = match day {
+        Some(day) => vec![day],
+        None => entries(registry, year, None)?
+            .iter()
+            .map(|entry| entry.day)
+            .collect(),
+    };
+
+    let config = Config::load().map_err(|err| err.to_string())?;
+    let client = Client::new(&config).map_err(|err| err.to_string())?;
+
+    let mut failed = 0;
+    for day in days {
+        match client.fetch_input(year, day, &dir) {
+            Ok(true) => println!("{} Day {:02}  fetched", year, day),
+            Ok(false) => println!("{} Day {:02}  cached", year, day),
+            Err(err) => {
+                eprintln!("{} Day {:02}  {}", year, day, err);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("failed to fetch {} input(s)", failed));
+    }
+    Ok(())
+}
+
+/// Submits the answer of a part, or the answer of its solution if none was
+/// given. Answers which are known to be wrong are not submitted, the outcome
+/// is remembered in the `answers.toml` or `guesses.toml` file of the year.
+fn submit(
+    registry: &Registry,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    source: &InputSource,
+) -> Result<(), String> {
+    let dir = registry
+        .dir(year)
+        .ok_or_else(|| format!("{} has no directory to store answers in", year))?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let entry = entries(registry, year, Some(day))?[0];
+            let input = read_input(entry, source)?;
+            entry
+                .part(part)
+                .solve(&input)
+                .map_err(|err| format!("{} {}", year, err))?
+        }
+    };
+
+    let mut answers = registry.answers(year).map_err(|err| err.to_string())?;
+    match answers.get(day, part) {
+        Some(known) if known == answer => {
+            println!(
+                "{} Day {:02} {}  `{}` was accepted already",
+                year, day, part, answer
+            );
+            return Ok(());
+        }
+        Some(known) => {
+            return Err(format!(
+                "not submitting `{}`, the accepted answer is `{}`",
+                answer, known
+            ));
+        }
+        None => {}
+    }
+
+    let guesses_path = dir.join("guesses.toml");
+    let mut guesses = Guesses::load(&guesses_path).map_err(|err| err.to_string())?;
+    if let Some(rejection) = guesses.check(day, part, &answer, SystemTime::now()) {
+        return Err(format!("not submitting `{}`, {}", answer, rejection));
+    }
+
+    let config = Config::load().map_err(|err| err.to_string())?;
+    let client = Client::new(&config).map_err(|err| err.to_string())?;
+    let outcome = client
+        .submit(year, day, part, &answer)
+        .map_err(|err| err.to_string())?;
+    let message = format!("{} Day {:02} {}  `{}` {}", year, day, part, answer, outcome);
+
+    match outcome {
+        Outcome::Correct => {
+            answers.set(day, part, answer);
+            answers
+                .save(&dir.join("answers.toml"))
+                .map_err(|err| err.to_string())?;
+            println!("{}", message);
+        }
+        Outcome::AlreadySolved => println!("{}", message),
+        Outcome::Wrong { hint, wait } => {
+            guesses.add(day, part, answer, hint);
+            if let Some(wait) = wait {
+                guesses.wait(day, part, SystemTime::now(), wait);
+            }
+            guesses.save(&guesses_path).map_err(|err| err.to_string())?;
+            return Err(message);
+        }
+        Outcome::Wait(wait) => {
+            guesses.wait(day, part, SystemTime::now(), wait);
+            guesses.save(&guesses_path).map_err(|err| err.to_string())?;
+            return Err(message);
+        }
+    }
+    Ok(())
+}
+
+/// Benchmarks every day on its embedded input and rewrites the benchmark
+/// tables in the README of the workspace. A day which fails is shown as not
+/// available.
+fn write_readme(
+    registry: &Registry,
+    config: &BenchConfig,
+    machine: &str,
+    history: &Recorder,
+) -> Result<(), String> {
+    let path = root().join("README.md");
+    let contents = fs::read_to_string(&path).map_err(|err| err.to_string())?;
+
+    let years: Vec<readme::Year> = registry
+        .years()
+        .into_iter()
+        .map(|year| {
+            let days = registry
+                .year(year)
+                .map(|entry| {
+                    eprintln!("{} Day {:02}", year, entry.day);
+                    let report = entry
+                        .bench(entry.input, config)
+                        .map_err(|err| format!("{} {}", year, err))
+                        .and_then(|report| history.record(entry, &report).map(|_| report))
+                        .map_err(|err| eprintln!("{}", err))
+                        .ok();
+                    (entry.day, report)
+                })
+                .collect();
+            (year, days)
+        })
+        .collect();
+
+    let contents = readme::replace_region(&contents, &readme::tables(&years, machine))?;
+    fs::write(&path, contents).map_err(|err| err.to_string())?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
+
+/// Reads the input of the entry and prints the statistics of every part.
+/// The results are added to the history if there is one.
+fn bench(
+    entry: &Entry,
+    source: &InputSource,
+    config: &BenchConfig,
+    history: Option<&Recorder>,
+) -> Result<(), String> {
+    let input = read_input(entry, source)?;
+
+    let report = entry
+        .bench(&input, config)
+        .map_err(|err| format!("{} {}", entry.year, err))?;
+
+    println!("Parse  {}", report.parse);
+    for part in Part::ALL.iter().copied() {
+        println!("{}  {}", part, report.part(part));
+    }
+
+    // Alternatives parse the input themselves, so they are compared with the
+    // registered solution including parsing
+    for part in Part::ALL.iter().copied() {
+        let implementations = entry.implementations(part);
+        if implementations.len() < 2 {
+            continue;
+        }
+
+        println!("{} implementations, including parsing", part);
+        let width = implementations.iter().map(|imp| imp.name.len()).max();
+        for implementation in &implementations {
+            let stats = implementation
+                .bench(&input, config)
+                .map_err(|err| format!("{} {}", entry.year, err))?;
+            println!(
+                "  {:<width$}  {}",
+                implementation.name,
+                stats,
+                width = width.unwrap_or_default()
+            );
+        }
+    }
+
+    match history {
+        Some(history) => history.record(entry, &report),
+        None => Ok(()),
+    }
+}
+
+/// Adds the benchmark results of the checked out commit to the history file of
+/// the workspace.
+struct Recorder {
+    path: PathBuf,
+    commit: String,
+    date: String,
+}
+
+impl Recorder {
+    fn new() -> Self {
+        Self {
+            path: root().join(HISTORY),
+            commit: commit(),
+            date: aoc_lib::utc_date(SystemTime::now()),
+        }
+    }
+
+    fn record(&self, entry: &Entry, report: &BenchReport) -> Result<(), String> {
+        let records = Record::from_report(&self.commit, &self.date, entry.year, entry.day, report);
+        History::append(&self.path, &records)
+            .map_err(|err| format!("could not write {}: {}", self.path.display(), err))
+    }
+}
+
+/// Returns the short hash of the checked out commit, with a `-dirty` suffix if
+/// tracked files were changed since, or `unknown` outside of a git repository.
+fn commit() -> String {
+    let git = |args: &[&str]| {
+        process::Command::new("git")
+            .args(args)
+            .current_dir(root())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(changes) if changes.is_empty() => hash,
+            _ => format!("{}-dirty", hash),
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Compares the last benchmarks of a commit, or of the last run, with those of
+/// the baseline commit. Fails if any part got more than `threshold` percent
+/// slower.
+fn compare(baseline: &str, commit: Option<&str>, threshold: f64) -> Result<(), String> {
+    let path = root().join(HISTORY);
+    let history = History::load(&path).map_err(|err| err.to_string())?;
+    let commit = commit
+        .or_else(|| history.last_commit())
+        .ok_or_else(|| format!("{} has no benchmarks yet, run `aoc bench`", path.display()))?;
+    let changes = history
+        .compare(baseline, commit)
+        .map_err(|err| err.to_string())?;
+
+    println!("{} -> {}", baseline, commit);
+    let mut slower = 0;
+    for change in &changes {
+        if change.slower_than(threshold) {
+            println!("{}  slower", change);
+            slower += 1;
+        } else {
+            println!("{}", change);
+        }
+    }
+
+    if slower > 0 {
+        return Err(format!(
+            "{} of {} part(s) got more than {}% slower",
+            slower,
+            changes.len(),
+            threshold
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,159 @@
+//! Renders the benchmark tables of the README from real runs.
+
+use std::{env, fmt::Write, fs, thread, time::Duration};
+
+use aoc_lib::BenchReport;
+
+/// Marks the start of the region of the README which is rewritten.
+pub const BEGIN: &str = "<!-- bench:begin -->";
+/// Marks the end of the region of the README which is rewritten.
+pub const END: &str = "<!-- bench:end -->";
+
+/// The benchmarked days of a year, without a report for a day which failed.
+pub type Year = (u16, Vec<(u8, Option<BenchReport>)>);
+
+/// Renders a table of the median durations per year, newest year and day
+/// first, each with a total row. Days which failed show `N.A.` and are left
+/// out of the totals.
+pub fn tables(years: &[Year], machine: &str) -> String {
+    let mut s = format!("Ran on {}.\n", machine);
+
+    for (year, days) in years.iter().rev() {
+        let _ = write!(
+            s,
+            "\n## [Advent of Code {0}](https://adventofcode.com/{0})\n\n\
+             | Day | Parse | Part 1 | Part 2 | Total |\n\
+             |-----|------:|-------:|-------:|------:|\n",
+            year
+        );
+
+        let mut total = [Duration::ZERO; 4];
+        for (day, report) in days.iter().rev() {
+            let link = format!("[{1}](https://adventofcode.com/{0}/day/{1})", year, day);
+            match report {
+                Some(report) => {
+                    let row = medians(report);
+                    for (sum, median) in total.iter_mut().zip(row) {
+                        *sum += median;
+                    }
+                    write_row(&mut s, &link, row);
+                }
+                None => {
+                    let _ = writeln!(s, "| {} | *N.A.* | *N.A.* | *N.A.* | *N.A.* |", link);
+                }
+            }
+        }
+        write_row(&mut s, "Total", total);
+    }
+    s
+}
+
+/// Returns the median durations of parsing, both parts and their sum.
+fn medians(report: &BenchReport) -> [Duration; 4] {
+    let (parse, part1, part2) = (
+        report.parse.median,
+        report.part1.median,
+        report.part2.median,
+    );
+    [parse, part1, part2, parse + part1 + part2]
+}
+
+fn write_row(s: &mut String, day: &str, durations: [Duration; 4]) {
+    let _ = write!(s, "| {} |", day);
+    for duration in durations {
+        let _ = write!(s, " {:.3?} |", duration);
+    }
+    s.push('\n');
+}
+
+/// Replaces the contents between the [BEGIN] and [END] markers of the README.
+pub fn replace_region(readme: &str, contents: &str) -> Result<String, String> {
+    let missing = |marker| format!("the README has no `{}` marker", marker);
+    let begin = readme.find(BEGIN).ok_or_else(|| missing(BEGIN))? + BEGIN.len();
+    let end = readme[begin..].find(END).ok_or_else(|| missing(END))? + begin;
+
+    Ok(format!(
+        "{}\n{}\n{}",
+        &readme[..begin],
+        contents.trim_end(),
+        &readme[end..]
+    ))
+}
+
+/// Describes the CPU and operating system this runs on, like
+/// `AMD Ryzen 7 3700X 8-Core Processor (16 threads), linux`.
+pub fn machine() -> String {
+    let cpu = fs::read_to_string("/proc/cpuinfo")
+        .ok()
+        .and_then(|info| {
+            info.lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(key, _)| key.trim() == "model name")
+                .map(|(_, name)| name.split_whitespace().collect::<Vec<_>>().join(" "))
+        })
+        .unwrap_or_else(|| format!("an unknown {} CPU", env::consts::ARCH));
+
+    match thread::available_parallelism().map(usize::from) {
+        Ok(1) => format!("{} (1 thread), {}", cpu, env::consts::OS),
+        Ok(threads) => format!("{} ({} threads), {}", cpu, threads, env::consts::OS),
+        Err(_) => format!("{}, {}", cpu, env::consts::OS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_lib::Stats;
+
+    use super::*;
+
+    fn report(millis: [u64; 3]) -> BenchReport {
+        let stats = |millis| {
+            let duration = Duration::from_millis(millis);
+            Stats::from_samples(&[duration]).unwrap()
+        };
+        BenchReport {
+            parse: stats(millis[0]),
+            part1: stats(millis[1]),
+            part2: stats(millis[2]),
+        }
+    }
+
+    #[test]
+    fn tables_works() {
+        let years = vec![
+            (2015, vec![(1, Some(report([0, 1, 2])))]),
+            (2020, vec![(9, Some(report([1, 2, 3]))), (19, None)]),
+        ];
+
+        assert_eq!(
+            tables(&years, "a toaster"),
+            "Ran on a toaster.\n\
+             \n\
+             ## [Advent of Code 2020](https://adventofcode.com/2020)\n\
+             \n\
+             | Day | Parse | Part 1 | Part 2 | Total |\n\
+             |-----|------:|-------:|-------:|------:|\n\
+             | [19](https://adventofcode.com/2020/day/19) | *N.A.* | *N.A.* | *N.A.* | *N.A.* |\n\
+             | [9](https://adventofcode.com/2020/day/9) | 1.000ms | 2.000ms | 3.000ms | 6.000ms |\n\
+             | Total | 1.000ms | 2.000ms | 3.000ms | 6.000ms |\n\
+             \n\
+             ## [Advent of Code 2015](https://adventofcode.com/2015)\n\
+             \n\
+             | Day | Parse | Part 1 | Part 2 | Total |\n\
+             |-----|------:|-------:|-------:|------:|\n\
+             | [1](https://adventofcode.com/2015/day/1) | 0.000ns | 1.000ms | 2.000ms | 3.000ms |\n\
+             | Total | 0.000ns | 1.000ms | 2.000ms | 3.000ms |\n"
+        );
+    }
+
+    #[test]
+    fn replace_region_works() {
+        let readme = format!("# Title\n{}\nold\n{}\nrest\n", BEGIN, END);
+        assert_eq!(
+            replace_region(&readme, "new\n"),
+            Ok(format!("# Title\n{}\nnew\n{}\nrest\n", BEGIN, END))
+        );
+        assert!(replace_region("# Title\n", "new").is_err());
+        assert!(replace_region(BEGIN, "new").is_err());
+    }
+}
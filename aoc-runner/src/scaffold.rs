@@ -0,0 +1,298 @@
+//! Generates the files of a new day, and of its year if it is new as well.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const DAY: &str = r#"//! [Day __DAY__](https://adventofcode.com/__YEAR__/day/__DAY__)
+
+__DOMAIN__use aoc_lib::{Part1, Part2};
+
+pub const INPUT: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/__DD__.txt"));
+
+#[derive(Default)]
+pub struct Day__DD__;
+
+impl Part1 for Day__DD__ {
+    type A = usize;
+
+    fn solve(&self, _input: &str) -> Self::A {
+        todo!()
+    }
+}
+
+impl Part2 for Day__DD__ {
+    type B = usize;
+
+    fn solve(&self, _input: &str) -> Self::B {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day__DD__, INPUT};
+
+    aoc_lib::test_table! {
+        Day__DD__;
+        part1_example: Part1 { aoc_lib::example!("__DD__.txt") => 0 },
+        part2_example: Part2 { aoc_lib::example!("__DD__.txt") => 0 },
+        part1_answer: Part1 { INPUT => 0 },
+        part2_answer: Part2 { INPUT => 0 },
+    }
+}
+"#;
+
+const BIN: &str = "fn main() {
+    aoc___YEAR__::solve_print(__DAY__);
+}
+";
+
+const CARGO_TOML: &str = r#"[package]
+name = "aoc-__YEAR__"
+version = "0.1.0"
+authors = ["Oktay Dinler <oktay@oktaydinler.xyz>"]
+edition = "2018"
+
+[dependencies]
+aoc-lib = { path = "../aoc-lib" }
+"#;
+
+const LIB: &str = r#"//! Solutions for the [Advent of Code __YEAR__](https://adventofcode.com/__YEAR__) puzzles.
+
+pub mod day__DD__;
+
+use aoc_lib::Registry;
+
+pub const YEAR: u16 = __YEAR__;
+
+/// Registers every solution of this year.
+pub fn register(registry: &mut Registry) {
+    registry.set_dir(YEAR, env!("CARGO_MANIFEST_DIR"));
+    registry.register(YEAR, __DAY__, day__DD__::INPUT, day__DD__::Day__DD__);
+}
+
+/// Runs and prints the answers of a single day of this year, see
+/// [aoc_lib::solve_print](aoc_lib::solve_print).
+pub fn solve_print(day: u8) {
+    aoc_lib::solve_print(register, YEAR, day);
+}
+"#;
+
+const ANSWERS: &str = "# Answers to the puzzle inputs in `inputs/`, checked by `aoc verify`.\n";
+
+/// Creates the files of a new day in the workspace at `root` and registers it,
+/// creating the crate of the year and registering it with the runner first if
+/// it does not exist yet. Returns the created and changed files.
+pub fn new_day(root: &Path, year: u16, day: u8, domain: bool) -> io::Result<Vec<PathBuf>> {
+    let fill = |template: &str| {
+        template
+            .replace(
+                "__DOMAIN__",
+                if domain { "pub mod domain;\n\n" } else { "" },
+            )
+            .replace("__YEAR__", &year.to_string())
+            .replace("__DD__", &format!("{:02}", day))
+            .replace("__DAY__", &day.to_string())
+    };
+
+    let krate = root.join(format!("aoc-{}", year));
+    let module = krate.join(format!("src/day{:02}", day));
+    if module.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+
+    let mut files = Vec::new();
+    let lib = krate.join("src/lib.rs");
+    if krate.exists() {
+        let mut source = fs::read_to_string(&lib)?;
+        insert_sorted(
+            &mut source,
+            "pub mod day",
+            &format!("pub mod day{:02};", day),
+        )?;
+        insert_sorted(
+            &mut source,
+            "    registry.register",
+            &fill("    registry.register(YEAR, __DAY__, day__DD__::INPUT, day__DD__::Day__DD__);"),
+        )?;
+        write(&mut files, &lib, &source)?;
+    } else {
+        write(&mut files, &krate.join("Cargo.toml"), &fill(CARGO_TOML))?;
+        write(&mut files, &lib, &fill(LIB))?;
+        write(&mut files, &krate.join("answers.toml"), ANSWERS)?;
+        register_year(&mut files, root, year)?;
+    }
+
+    write(&mut files, &module.join("mod.rs"), &fill(DAY))?;
+    if domain {
+        write(&mut files, &module.join("domain.rs"), "")?;
+    }
+    write(
+        &mut files,
+        &krate.join(format!("src/bin/{:02}/main.rs", day)),
+        &fill(BIN),
+    )?;
+
+    for input in [
+        format!("inputs/{:02}.txt", day),
+        format!("inputs/examples/{:02}.txt", day),
+    ] {
+        let input = krate.join(input);
+        if !input.exists() {
+            write(&mut files, &input, "")?;
+        }
+    }
+    Ok(files)
+}
+
+/// Adds the crate of a new year to the dependencies and registry of the runner.
+fn register_year(files: &mut Vec<PathBuf>, root: &Path, year: u16) -> io::Result<()> {
+    let manifest = root.join("aoc-runner/Cargo.toml");
+    let mut source = fs::read_to_string(&manifest)?;
+    insert_sorted(
+        &mut source,
+        "aoc-",
+        &format!("aoc-{0} = {{ path = \"../aoc-{0}\" }}", year),
+    )?;
+    write(files, &manifest, &source)?;
+
+    let main = root.join("aoc-runner/src/main.rs");
+    let mut source = fs::read_to_string(&main)?;
+    insert_sorted(
+        &mut source,
+        "    aoc_",
+        &format!("    aoc_{}::register(&mut registry);", year),
+    )?;
+    write(files, &main, &source)
+}
+
+/// Inserts the line among the consecutive lines which start with the prefix,
+/// keeping them ordered by the first number which follows the prefix.
+fn insert_sorted(source: &mut String, prefix: &str, line: &str) -> io::Result<()> {
+    let key = |line: &str| -> Option<u32> {
+        let rest = line
+            .strip_prefix(prefix)?
+            .trim_start_matches(|c: char| !c.is_ascii_digit());
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        rest[..digits].parse().ok()
+    };
+    let new = key(line).expect("the line starts with the prefix");
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|l| key(l).is_some())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no line starting with `{}` to insert after", prefix.trim()),
+            )
+        })?;
+    let index = lines[..=last]
+        .iter()
+        .position(|l| key(l).is_some_and(|k| k > new))
+        .unwrap_or(last + 1);
+
+    lines.insert(index, line);
+    *source = lines.join("\n") + "\n";
+    Ok(())
+}
+
+fn write(files: &mut Vec<PathBuf>, path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    files.push(path.to_path_buf());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// Creates a workspace with a runner which knows the 2015 crate.
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc-runner/src")).unwrap();
+        fs::write(
+            root.join("aoc-runner/Cargo.toml"),
+            "[dependencies]\naoc-lib = { path = \"../aoc-lib\" }\naoc-2015 = { path = \"../aoc-2015\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc-runner/src/main.rs"),
+            "fn registry() -> Registry {\n    let mut registry = Registry::default();\n    aoc_2015::register(&mut registry);\n    registry\n}\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn new_day_creates_year() {
+        let root = workspace("aoc-runner-new-day-creates-year");
+        new_day(&root, 2021, 3, true).unwrap();
+
+        let lib = fs::read_to_string(root.join("aoc-2021/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod day03;"));
+        assert!(lib.contains("registry.register(YEAR, 3, day03::INPUT, day03::Day03);"));
+        assert!(lib.contains("aoc_lib::solve_print(register, YEAR, day);"));
+        assert!(fs::read_to_string(root.join("aoc-2021/src/day03/mod.rs"))
+            .unwrap()
+            .starts_with("//! [Day 3](https://adventofcode.com/2021/day/3)\n\npub mod domain;\n\nuse aoc_lib"));
+        assert!(root.join("aoc-2021/src/day03/domain.rs").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("aoc-2021/src/bin/03/main.rs")).unwrap(),
+            "fn main() {\n    aoc_2021::solve_print(3);\n}\n"
+        );
+        assert!(root.join("aoc-2021/inputs/03.txt").exists());
+        assert!(root.join("aoc-2021/inputs/examples/03.txt").exists());
+
+        assert!(fs::read_to_string(root.join("aoc-runner/Cargo.toml"))
+            .unwrap()
+            .ends_with(
+                "aoc-2015 = { path = \"../aoc-2015\" }\naoc-2021 = { path = \"../aoc-2021\" }\n"
+            ));
+        assert!(fs::read_to_string(root.join("aoc-runner/src/main.rs"))
+            .unwrap()
+            .contains(
+                "aoc_2015::register(&mut registry);\n    aoc_2021::register(&mut registry);\n"
+            ));
+    }
+
+    #[test]
+    fn new_day_registers_in_order() {
+        let root = workspace("aoc-runner-new-day-registers-in-order");
+        new_day(&root, 2021, 10, false).unwrap();
+        new_day(&root, 2021, 2, false).unwrap();
+        let lib = root.join("aoc-2021/src/lib.rs");
+        let source = fs::read_to_string(&lib).unwrap().replace(
+            "register(YEAR, 10, day10::INPUT, day10::Day10)",
+            "register_with_params(YEAR, 10, day10::INPUT, day10::Day10::default())",
+        );
+        fs::write(&lib, source).unwrap();
+        new_day(&root, 2021, 25, false).unwrap();
+
+        let lib = fs::read_to_string(root.join("aoc-2021/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod day02;\npub mod day10;\npub mod day25;\n"));
+        let days: Vec<_> = lib
+            .lines()
+            .filter_map(|line| line.strip_prefix("    registry.register"))
+            .map(|line| line.split(", ").nth(1).unwrap())
+            .collect();
+        assert_eq!(days, vec!["2", "10", "25"]);
+        assert!(!root.join("aoc-2021/src/day02/domain.rs").exists());
+
+        assert!(new_day(&root, 2021, 2, false).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! Re-runs a day whenever one of its input files changes.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_lib::{Entry, InputSource, SolveReport, Status};
+
+/// An input file with the reports of the last run on it.
+struct Watched {
+    path: PathBuf,
+    /// When the file was last modified and its length, or `None` if it does
+    /// not exist.
+    stamp: Option<(Option<SystemTime>, u64)>,
+    reports: Option<Vec<SolveReport>>,
+}
+
+impl Watched {
+    /// Runs the entry on the file if it changed since the last poll, and
+    /// returns what should be printed.
+    fn poll(&mut self, entry: &Entry) -> Option<String> {
+        let stamp = fs::metadata(&self.path)
+            .ok()
+            .map(|metadata| (metadata.modified().ok(), metadata.len()));
+        if self.reports.is_some() && stamp == self.stamp {
+            return None;
+        }
+        self.stamp = stamp;
+
+        let header = format!(
+            "{} Day {:02} on {}\n",
+            entry.year,
+            entry.day,
+            self.path.display()
+        );
+        match entry.read_input(&InputSource::File(self.path.clone())) {
+            Ok(input) => {
+                let reports = entry.reports(&input);
+                let output = header + &render(self.reports.as_deref(), &reports);
+                self.reports = Some(reports);
+                Some(output)
+            }
+            Err(err) => {
+                self.reports = Some(Vec::new());
+                Some(format!("{}could not read the input: {}\n", header, err))
+            }
+        }
+    }
+}
+
+/// Runs the entry on every file once, then again on every file which changed,
+/// checking for changes after every interval. Never returns.
+pub fn run(entry: &Entry, paths: &[PathBuf], interval: Duration) -> ! {
+    let mut watched: Vec<_> = paths
+        .iter()
+        .map(|path| Watched {
+            path: path.to_path_buf(),
+            stamp: None,
+            reports: None,
+        })
+        .collect();
+
+    let names: Vec<_> = paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect();
+    println!("Watching {}, press Ctrl-C to stop\n", names.join(" and "));
+    loop {
+        for file in &mut watched {
+            if let Some(output) = file.poll(entry) {
+                println!("{}", output);
+            }
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// Shows the answer and duration of parsing and every part, with the change in
+/// duration and the previous answer if it changed since the previous reports.
+fn render(previous: Option<&[SolveReport]>, reports: &[SolveReport]) -> String {
+    let before = |report: &SolveReport| {
+        previous?
+            .iter()
+            .find(|prev| prev.part == report.part && prev.status == Status::Solved)
+    };
+    let mut s = String::new();
+
+    if let Some(report) = reports.first() {
+        let change = before(report).map(|prev| (prev.parse, report.parse));
+        let _ = writeln!(s, "Parse  {}", timing(report.parse, change));
+    }
+    for report in reports {
+        if report.status != Status::Solved {
+            let _ = writeln!(
+                s,
+                "{}  {}: {}",
+                report.part,
+                report.status.to_string().to_uppercase(),
+                report.answer
+            );
+            continue;
+        }
+
+        let prev = before(report);
+        let change = prev.map(|prev| (prev.duration, report.duration));
+        let _ = write!(
+            s,
+            "{}  {}  {}",
+            report.part,
+            report.answer,
+            timing(report.duration, change)
+        );
+        match prev {
+            Some(prev) if prev.answer != report.answer => {
+                let _ = writeln!(s, "  was {}", prev.answer);
+            }
+            _ => s.push('\n'),
+        }
+    }
+    s
+}
+
+/// Formats a duration, with its change in percent from the previous duration
+/// if there is one.
+fn timing(duration: Duration, change: Option<(Duration, Duration)>) -> String {
+    match change {
+        Some((before, after)) => {
+            let before = before.as_secs_f64().max(f64::MIN_POSITIVE);
+            let percent = (after.as_secs_f64() / before - 1.0) * 100.0;
+            format!("({:.3?}, {:+.1}%)", duration, percent)
+        }
+        None => format!("({:.3?})", duration),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_lib::Part;
+
+    use super::*;
+
+    fn report(part: Part, answer: &str, millis: u64, status: Status) -> SolveReport {
+        SolveReport {
+            year: 2020,
+            day: 23,
+            part,
+            answer: answer.into(),
+            parse: Duration::from_millis(1),
+            duration: Duration::from_millis(millis),
+            allocs: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn render_first_run() {
+        let reports = [
+            report(Part::One, "92658374", 2, Status::Solved),
+            report(Part::Two, "never", 0, Status::NoAnswer),
+        ];
+
+        assert_eq!(
+            render(None, &reports),
+            "Parse  (1.000ms)\nPart1  92658374  (2.000ms)\nPart2  NO_ANSWER: never\n"
+        );
+    }
+
+    #[test]
+    fn render_diffs_previous_run() {
+        let previous = [
+            report(Part::One, "92658374", 2, Status::Solved),
+            report(Part::Two, "149245887792", 100, Status::Solved),
+        ];
+        let reports = [
+            report(Part::One, "92658374", 3, Status::Solved),
+            report(Part::Two, "42", 50, Status::Solved),
+        ];
+
+        assert_eq!(
+            render(Some(&previous), &reports),
+            "Parse  (1.000ms, +0.0%)\n\
+             Part1  92658374  (3.000ms, +50.0%)\n\
+             Part2  42  (50.000ms, -50.0%)  was 149245887792\n"
+        );
+    }
+}